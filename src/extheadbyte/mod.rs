@@ -1,5 +1,523 @@
 //! The Extended Head Byte format, capable of storing arbitrarily large signed integers and decimal fractions.
 //!
 //! This format is strictly equivalent to Head Byte if there are 15 or less follow-up (1 exponent + 14 coefficient) bytes (the only exception). Otherwise, both the exponent and the **number** of coefficient bytes are stored using the Linked Bytes format.
+//!
+//! # Layout
+//! The Extended Head Byte reuses the sign and exponent presence bits of the Head Byte. The remaining 6 bits either store the number of follow-up bytes, just like in Head Byte, if it's 15 or less, or are all set (`0b111111`), which marks the number as **extended**. The values from 16 to 62 are reserved and never appear in a valid Extended Head Byte. An extended number is laid out as follows:
+//! - the Extended Head Byte itself;
+//! - the exponent as a Linked Bytes number, if the exponent bit is set. Since Linked Bytes are unsigned, the sign is folded into the least significant bit using zigzag encoding: `0, -1, 1, -2, 2...` are stored as `0, 1, 2, 3, 4...`;
+//! - the number of coefficient bytes as a Linked Bytes number;
+//! - the coefficient bytes themselves, in little endian byte order.
+//!
+//! An Extended Head Byte number is only extended if it has to be, i.e. if it has more than 15 follow-up bytes or if its exponent doesn't fit into the range from -127 to +127, which is what the Head Byte exponent can store.
+
+mod ops;
+
+use crate::{
+    Sign,
+    headbyte::{HBNum, HeadByte, Exponent, InvalidExponentError},
+    linkedbytes::{LBNum, LBInt},
+};
+use core::{
+    convert::{TryFrom, TryInto},
+    fmt::{self, Formatter, Display},
+};
+use alloc::vec::Vec;
+
+/// The Extended Head Byte format, capable of storing arbitrarily large signed integers and decimal fractions.
+///
+/// See the [module-level documentation][modehb] for more.
+///
+/// [modehb]: index.html "bigbit::extheadbyte — the Extended Head Byte format, capable of storing arbitrarily large signed integers and decimal fractions"
+#[derive(Clone, Debug)]
+pub struct EHBNum {
+    hb: ExtHeadByte,
+    exponent: Option<ExtExponent>,
+    bytes: Vec<u8>
+}
+impl EHBNum {
+    /// Constructs a new `EHBNum` from the head byte, exponent and the coefficients.
+    ///
+    /// The length of the coefficient storage and the presence of the exponent override the value in the head byte, including whether the number is extended or not. Unlike [`HBNum::from_raw_parts`][0], this never panics, since there is no size limit.
+    ///
+    /// If there is neither an exponent nor any coefficients, the head byte is kept as-is, which is how the zero, NaN and infinity special values are constructed.
+    ///
+    /// [0]: ../headbyte/struct.HBNum.html#method.from_raw_parts "HBNum::from_raw_parts — constructs a new HBNum from the head byte, exponent and the coefficients"
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // The byte count is checked against the non-extended limit first.
+    pub fn from_raw_parts(hb: ExtHeadByte, exponent: Option<ExtExponent>, coefficient_bytes: Vec<u8>) -> Self {
+        let hb = if exponent.is_none() && coefficient_bytes.is_empty() {
+            hb.with_num_bytes(0)
+        } else {
+            let fits_into_hb = match &exponent {
+                Some(exponent) => Exponent::try_from(exponent).is_ok(),
+                None => true,
+            };
+            let num_bytes = coefficient_bytes.len() + exponent.is_some() as usize;
+            let hb = hb.with_exponent_bit(exponent.is_some());
+            if fits_into_hb && num_bytes <= ExtHeadByte::MAX_NON_EXTENDED_BYTES as usize {
+                hb.with_num_bytes(num_bytes as u8)
+            } else {
+                hb.with_num_bytes(ExtHeadByte::EXTENDED)
+            }
+        };
+        Self {hb, exponent, bytes: coefficient_bytes}
+    }
+
+    /// Returns the head byte.
+    ///
+    /// The results of inspecting the head byte are reliable and always match the properties of the actual value of the entire number, including whether the number is extended or not.
+    #[inline(always)]
+    pub const fn headbyte(&self) -> ExtHeadByte {
+        self.hb
+    }
+    /// Returns the exponent, or `None` if it's not used (mainly the case for integers).
+    #[inline(always)]
+    pub const fn exponent(&self) -> Option<&ExtExponent> {
+        self.exponent.as_ref()
+    }
+    /// Returns `true` if the number uses the extended layout, `false` if it's laid out exactly like a Head Byte number.
+    #[inline(always)]
+    pub const fn is_extended(&self) -> bool {
+        self.hb.is_extended()
+    }
+    /// Returns the number of coefficient bytes.
+    #[inline(always)]
+    pub fn num_coefficients(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns an iterator over the coefficients in little endian byte order.
+    #[inline(always)]
+    pub fn coefficient_le_iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.bytes.iter().copied()
+    }
+    /// Returns an iterator over the coefficients in big endian byte order.
+    #[inline(always)]
+    pub fn coefficient_be_iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.coefficient_le_iter().rev()
+    }
+
+    /// Encodes the number into its byte representation, as specified by the format.
+    ///
+    /// If the number is not extended, the result is byte-for-byte identical to the Head Byte representation of the same number.
+    #[cfg_attr(feature = "clippy", allow(clippy::missing_panics_doc))] // Non-extended numbers only ever get exponents which fit into a Head Byte.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(1 + self.bytes.len());
+        result.push(self.hb.into_inner());
+        if self.is_extended() {
+            if let Some(exponent) = &self.exponent {
+                exponent.to_zigzag().encode_into(&mut result);
+            }
+            LBNum::from(self.bytes.len()).encode_into(&mut result);
+        } else if let Some(exponent) = &self.exponent {
+            let exponent = Exponent::try_from(exponent)
+                .expect("non-extended number with an exponent out of Head Byte range");
+            result.push(exponent.into_inner());
+        }
+        result.extend_from_slice(&self.bytes);
+        result
+    }
+    /// Decodes a number from the beginning of a byte slice, returning it along with the number of bytes it occupied.
+    ///
+    /// Any bytes after the number are left alone, which allows parsing several numbers stored back-to-back. For a version which rejects them, see [`from_bytes`][0].
+    ///
+    /// # Errors
+    /// See [`EHBDecodeError`][1].
+    ///
+    /// [0]: #method.from_bytes "from_bytes — decodes a number which occupies the entire byte slice"
+    /// [1]: enum.EHBDecodeError.html "EHBDecodeError — the error type for decoding Extended Head Byte numbers"
+    pub fn parse(op: &[u8]) -> Result<(Self, usize), EHBDecodeError> {
+        let (&hb, mut rest) = op.split_first().ok_or(EHBDecodeError::UnexpectedEnd)?;
+        let hb = ExtHeadByte::from(hb);
+        if hb.is_nan() || hb.is_infinite() || hb == ExtHeadByte::ZERO {
+            return Ok((Self {hb, exponent: None, bytes: Vec::new()}, 1));
+        }
+        let (exponent, num_coefficients) = if hb.is_extended() {
+            let exponent = if hb.exponent_bit() {
+                let (zigzag, len) = LBNum::decode_prefix(rest).map_err(|_| EHBDecodeError::UnexpectedEnd)?;
+                rest = &rest[len..];
+                Some(ExtExponent::from_zigzag(zigzag))
+            } else {None};
+            let (num_coefficients, len) = LBNum::decode_prefix(rest).map_err(|_| EHBDecodeError::UnexpectedEnd)?;
+            rest = &rest[len..];
+            let num_coefficients = usize::try_from(&num_coefficients)
+                .map_err(|_| EHBDecodeError::UnexpectedEnd)?;
+            (exponent, num_coefficients)
+        } else if hb.num_bytes() > ExtHeadByte::MAX_NON_EXTENDED_BYTES {
+            return Err(EHBDecodeError::InvalidHeadByte);
+        } else if hb.exponent_bit() {
+            let (&exponent, exponent_rest) = rest.split_first().ok_or(EHBDecodeError::UnexpectedEnd)?;
+            rest = exponent_rest;
+            let exponent = Exponent::try_from(exponent)?;
+            (Some(ExtExponent::from(exponent)), hb.num_bytes() as usize - 1)
+        } else {
+            (None, hb.num_bytes() as usize)
+        };
+        let bytes = rest.get(..num_coefficients).ok_or(EHBDecodeError::UnexpectedEnd)?;
+        let consumed = op.len() - rest.len() + num_coefficients;
+        Ok((Self {hb, exponent, bytes: bytes.to_vec()}, consumed))
+    }
+    /// Decodes a number which occupies the entire byte slice.
+    ///
+    /// # Errors
+    /// See [`EHBDecodeError`][0]. Unlike [`parse`][1], any bytes left over after the number are treated as an error.
+    ///
+    /// [0]: enum.EHBDecodeError.html "EHBDecodeError — the error type for decoding Extended Head Byte numbers"
+    /// [1]: #method.parse "parse — decodes a number from the beginning of a byte slice"
+    pub fn from_bytes(op: &[u8]) -> Result<Self, EHBDecodeError> {
+        let (result, consumed) = Self::parse(op)?;
+        if consumed != op.len() {
+            return Err(EHBDecodeError::TrailingBytes);
+        }
+        Ok(result)
+    }
+    /// Writes the byte representation of the number into the specified writer.
+    ///
+    /// See [`to_bytes`][0] for the layout.
+    ///
+    /// # Errors
+    /// Any I/O errors returned by the writer are passed through.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into its byte representation"
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+}
+impl From<HBNum> for EHBNum {
+    /// Converts a Head Byte number into an Extended Head Byte one. This never fails, since the Extended Head Byte format is a superset of Head Byte.
+    fn from(op: HBNum) -> Self {
+        let hb = ExtHeadByte::from(op.headbyte().into_inner());
+        let exponent = op.exponent().map(ExtExponent::from);
+        Self::from_raw_parts(hb, exponent, op.coefficient_le_iter().collect())
+    }
+}
+impl TryFrom<EHBNum> for HBNum {
+    type Error = TryFromEHBError;
+
+    /// Converts an Extended Head Byte number into a Head Byte one.
+    ///
+    /// # Errors
+    /// Fails if the exponent is out of Head Byte range or if there are more than 63 follow-up bytes.
+    fn try_from(op: EHBNum) -> Result<Self, TryFromEHBError> {
+        let exponent = op.exponent.as_ref()
+            .map(Exponent::try_from)
+            .transpose()
+            .map_err(|_| TryFromEHBError)?;
+        let num_bytes = op.bytes.len() + exponent.is_some() as usize;
+        if num_bytes > HeadByte::NUM_COEFFICIENTS_MASK as usize {
+            return Err(TryFromEHBError);
+        }
+        let hb = HeadByte::from(op.hb.with_num_bytes(0).into_inner());
+        Ok(Self::from_raw_parts(hb, exponent, op.bytes))
+    }
+}
+
+/// The Extended Head Byte itself, containing information about the sign, presence of the exponent and either the number of follow-up bytes or the fact that the number is extended.
+///
+/// Follows the newtype pattern, meaning that it can be unwrapped into the inner byte.
+#[repr(transparent)]
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct ExtHeadByte(u8);
+
+impl ExtHeadByte {
+    /// The sign bit mask.
+    ///
+    /// Use [`sign`][0] to easily retreive the sign.
+    ///
+    /// [0]: #method.sign "sign — retreives the sign from an Extended Head Byte"
+    pub const SIGN_MASK: u8 = HeadByte::SIGN_MASK;
+    /// The mask used for retreiving the absolute value of the number.
+    ///
+    /// Use [`abs`][0] or bitwise-`AND` (`&`) this with the Extended Head Byte to retreive the absolute value.
+    ///
+    /// [0]: #method.abs "abs — retreives the absolute value from the number whose Extended Head Byte is self"
+    pub const ABS_MASK: u8 = HeadByte::ABS_MASK;
+    /// The exponent presence bit mask.
+    ///
+    /// Use [`exponent_bit`][0] to easily retreive this.
+    ///
+    /// [0]: #method.exponent_bit "exponent_bit — checks whether the HAS_EXPONENT bit of the Extended Head Byte is set"
+    pub const HAS_EXPONENT_MASK: u8 = HeadByte::HAS_EXPONENT_MASK;
+    /// Mask for the number of follow-up bytes, which is also used to mark the number as extended.
+    ///
+    /// Use [`num_bytes`][0] and [`is_extended`][1] to easily retreive this.
+    ///
+    /// [0]: #method.num_bytes "num_bytes — fetches the number of bytes in a non-extended number which are supposed to follow the Extended Head Byte"
+    /// [1]: #method.is_extended "is_extended — checks whether the Extended Head Byte marks the number as extended"
+    pub const NUM_BYTES_MASK: u8 = HeadByte::NUM_COEFFICIENTS_MASK;
+    /// The value of the follow-up byte count field which marks the number as extended, i.e. storing the exponent and the number of coefficient bytes using Linked Bytes.
+    pub const EXTENDED: u8 = Self::NUM_BYTES_MASK;
+    /// The largest number of follow-up bytes which can be stored without making the number extended.
+    pub const MAX_NON_EXTENDED_BYTES: u8 = 15;
+    /// The **∞** (positive infinity) value for the Extended Head Byte. Same as [`HeadByte::INFINITY`][0].
+    ///
+    /// [0]: ../headbyte/struct.HeadByte.html#associatedconstant.INFINITY "HeadByte::INFINITY — the positive infinity value for the Head Byte"
+    pub const INFINITY: Self = Self(HeadByte::INFINITY.into_inner());
+    /// The **-∞** (negative infinity) value for the Extended Head Byte. Same as [`HeadByte::NEG_INFINITY`][0].
+    ///
+    /// [0]: ../headbyte/struct.HeadByte.html#associatedconstant.NEG_INFINITY "HeadByte::NEG_INFINITY — the negative infinity value for the Head Byte"
+    pub const NEG_INFINITY: Self = Self(HeadByte::NEG_INFINITY.into_inner());
+    /// The zero value. Same as [`HeadByte::ZERO`][0].
+    ///
+    /// [0]: ../headbyte/struct.HeadByte.html#associatedconstant.ZERO "HeadByte::ZERO — the zero value for the Head Byte"
+    pub const ZERO: Self = Self(HeadByte::ZERO.into_inner());
+    /// The NaN (Not-a-Number) value. Same as [`HeadByte::NAN`][0].
+    ///
+    /// [0]: ../headbyte/struct.HeadByte.html#associatedconstant.NAN "HeadByte::NAN — the NaN value for the Head Byte"
+    pub const NAN: Self = Self(HeadByte::NAN.into_inner());
+
+    /// Extracts the sign from an Extended Head Byte.
+    #[inline(always)]
+    pub fn sign(self) -> Sign {
+        Sign::from((self.0 & Self::SIGN_MASK) != 0)
+    }
+    /// Calculates the absolute value from the number whose Extended Head Byte is `self`.
+    #[inline(always)]
+    #[must_use = "this is not an in-place operation"]
+    pub const fn abs(self) -> Self {
+        Self(self.0 & Self::ABS_MASK)
+    }
+    /// Checks whether the `HAS_EXPONENT` bit of the Extended Head Byte is set, meaning either infinity or the presence of an actual exponent.
+    #[inline(always)]
+    pub const fn exponent_bit(self) -> bool {
+        (self.0 & Self::HAS_EXPONENT_MASK) != 0
+    }
+    /// Constructs an Extended Head Byte which has the same sign flag and follow-up byte count field as the one specified but also sets the exponent presence bit to a new value.
+    #[inline]
+    #[must_use = "this is not an in-place operation"]
+    pub const fn with_exponent_bit(self, op: bool) -> Self {
+        Self(match op {
+            true  => self.0 |  Self::HAS_EXPONENT_MASK,
+            false => self.0 & !Self::HAS_EXPONENT_MASK,
+        })
+    }
+    /// Checks whether the Extended Head Byte describes either positive or negative infinity.
+    #[inline(always)]
+    pub fn is_infinite(self) -> bool {
+        self.abs() == Self::INFINITY
+    }
+    /// Checks whether the Extended Head Byte describes a NaN value.
+    #[inline(always)]
+    pub const fn is_nan(self) -> bool {
+        self.0 == Self::NAN.0
+    }
+    /// Checks whether the Extended Head Byte marks the number as extended, i.e. whether the exponent and the number of coefficient bytes are stored using Linked Bytes.
+    #[inline(always)]
+    pub const fn is_extended(self) -> bool {
+        (self.0 & Self::NUM_BYTES_MASK) == Self::EXTENDED
+    }
+    /// Fetches the value of the follow-up byte count field.
+    ///
+    /// If the number is not extended, this is the number of bytes which are supposed to follow the Extended Head Byte, exactly like in Head Byte. Otherwise, it's [`EXTENDED`][0].
+    ///
+    /// [0]: #associatedconstant.EXTENDED "EXTENDED — the value of the follow-up byte count field which marks the number as extended"
+    #[inline(always)]
+    pub const fn num_bytes(self) -> u8 {
+        self.0 & Self::NUM_BYTES_MASK
+    }
+    /// Constructs an Extended Head Byte which has the same sign and exponent flags as the one specified but also sets the follow-up byte count field to a new value.
+    ///
+    /// # Panics
+    /// Panics if the number cannot fit into the follow-up byte count field.
+    #[inline]
+    #[must_use = "this is not an in-place operation"]
+    pub fn with_num_bytes(self, op: u8) -> Self {
+        assert!(
+            (!Self::NUM_BYTES_MASK & op) == 0,
+            "expected total number of bytes from 0 to 63, got {}", op,
+        );
+        Self((self.0 & !Self::NUM_BYTES_MASK) | op)
+    }
+
+    /// Consumes the value and returns the inner byte.
+    #[inline(always)]
+    pub const fn into_inner(self) -> u8 {
+        self.0
+    }
+}
+impl From<u8> for ExtHeadByte {
+    /// Wraps a byte into an Extended Head Byte.
+    #[inline(always)]
+    fn from(op: u8) -> Self {
+        Self(op)
+    }
+}
+impl From<ExtHeadByte> for u8 {
+    /// Consumes the Extended Head Byte and returns the underlying inner byte.
+    #[inline(always)]
+    fn from(op: ExtHeadByte) -> Self {
+        op.0
+    }
+}
+impl From<HeadByte> for ExtHeadByte {
+    /// Reinterprets a Head Byte as an Extended Head Byte. **If the Head Byte describes 16 or more follow-up bytes, the result is not valid** until the follow-up byte count field is overriden.
+    #[inline(always)]
+    fn from(op: HeadByte) -> Self {
+        Self(op.into_inner())
+    }
+}
+impl fmt::Debug for ExtHeadByte {
+    fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+        if self.is_nan() {
+            fmt.debug_tuple("ExtHeadByte").field(&"NaN").finish()
+        } else if self.is_infinite() {
+            fmt.debug_tuple("ExtHeadByte").field(&match self.sign() {
+                Sign::Positive => "Infinity",
+                Sign::Negative => "-Infinity"
+            }).finish()
+        } else if *self == Self::ZERO {
+            fmt.debug_tuple("ExtHeadByte").field(&"0").finish()
+        } else {
+            let mut ds = fmt.debug_struct("ExtHeadByte");
+            ds.field("sign", &crate::SignDisplayAsDebug(self.sign()));
+            ds.field("exponent_bit", &self.exponent_bit());
+            if self.is_extended() {
+                ds.field("extended", &true);
+            } else {
+                ds.field("num_bytes", &self.num_bytes());
+            }
+            ds.finish()
+        }
+    }
+}
+
+/// An exponent for the Extended Head Byte format.
+///
+/// Unlike the [Head Byte exponent][hbe], this one can be arbitrarily large, since its absolute value is stored as a Linked Bytes number. To retreive the real value of an EHB number, its stored value is multiplied by 10 raised to the power of this value.
+///
+/// There is no negative zero: constructing one produces a positive zero instead. Internally, this is a [signed Linked Bytes integer][lbi], which the exponent can be converted to and from.
+///
+/// [hbe]: ../headbyte/struct.Exponent.html "Exponent — an exponent for the Head Byte and Extended Head Byte formats"
+/// [lbi]: ../linkedbytes/lbint/struct.LBInt.html "LBInt — a signed integer stored using the Linked Bytes format"
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtExponent(LBInt);
+impl ExtExponent {
+    /// The zero exponent, i.e. `10^0`.
+    ///
+    /// This does not allocate memory.
+    pub const ZERO: Self = Self(LBInt::ZERO);
+
+    /// Constructs an exponent from its sign and absolute value. A negative zero is silently converted into a positive one.
+    #[inline]
+    pub fn new(sign: Sign, abs: LBNum) -> Self {
+        Self(LBInt::new(sign, abs))
+    }
+    /// Extracts the sign from the exponent. `Negative` means that the coefficient is multiplied by 10 raised to the power of `-n`, where `n` is the absolute value, and `Positive` simply means `10^n`.
+    #[inline(always)]
+    pub const fn sign(&self) -> Sign {
+        self.0.sign()
+    }
+    /// Returns the absolute value of the exponent.
+    #[inline(always)]
+    pub const fn abs(&self) -> &LBNum {
+        self.0.magnitude()
+    }
+    /// Consumes the exponent and returns its sign and absolute value.
+    #[inline(always)]
+    pub fn into_parts(self) -> (Sign, LBNum) {
+        self.0.into_parts()
+    }
+
+    /// Converts the exponent into the unsigned zigzag form used to store it in extended numbers: `0, -1, 1, -2, 2...` become `0, 1, 2, 3, 4...`.
+    #[inline]
+    pub fn to_zigzag(&self) -> LBNum {
+        self.0.to_zigzag()
+    }
+    /// Converts an exponent from the unsigned zigzag form used to store it in extended numbers.
+    ///
+    /// See [`to_zigzag`][0] for the details of the transformation.
+    ///
+    /// [0]: #method.to_zigzag "to_zigzag — converts the exponent into the unsigned zigzag form used to store it in extended numbers"
+    #[inline]
+    pub fn from_zigzag(op: LBNum) -> Self {
+        Self(LBInt::from_zigzag(op))
+    }
+}
+impl From<LBInt> for ExtExponent {
+    /// Uses a signed Linked Bytes integer as an exponent.
+    #[inline(always)]
+    fn from(op: LBInt) -> Self {
+        Self(op)
+    }
+}
+impl From<ExtExponent> for LBInt {
+    /// Returns the value of the exponent as a signed Linked Bytes integer.
+    #[inline(always)]
+    fn from(op: ExtExponent) -> Self {
+        op.0
+    }
+}
+impl From<Exponent> for ExtExponent {
+    /// Converts a Head Byte exponent into an Extended Head Byte one. This never fails, since the Extended Head Byte exponent has no size limit.
+    #[inline]
+    fn from(op: Exponent) -> Self {
+        Self::new(op.sign(), LBNum::from(op.abs().into_inner()))
+    }
+}
+impl TryFrom<&ExtExponent> for Exponent {
+    type Error = InvalidExponentError;
+
+    /// Converts an Extended Head Byte exponent into a Head Byte one.
+    ///
+    /// # Errors
+    /// Fails if the absolute value of the exponent is larger than 127.
+    fn try_from(op: &ExtExponent) -> Result<Self, InvalidExponentError> {
+        let abs: u8 = op.abs().try_into().map_err(|_| InvalidExponentError)?;
+        if abs > Self::ABS_MASK {return Err(InvalidExponentError);}
+        let sign_bit = match op.sign() {
+            Sign::Positive => 0,
+            Sign::Negative => Self::SIGN_MASK,
+        };
+        Self::try_from(sign_bit | abs)
+    }
+}
+
+/// The error type for decoding Extended Head Byte numbers from bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum EHBDecodeError {
+    /// The input ended before the entire number could be read.
+    UnexpectedEnd,
+    /// The follow-up byte count field of the Extended Head Byte had one of the reserved values, from 16 to 62.
+    InvalidHeadByte,
+    /// A non-extended number had `-0` as its exponent.
+    InvalidExponent(InvalidExponentError),
+    /// There were bytes left over after the number.
+    TrailingBytes,
+}
+impl From<InvalidExponentError> for EHBDecodeError {
+    #[inline(always)]
+    fn from(op: InvalidExponentError) -> Self {
+        Self::InvalidExponent(op)
+    }
+}
+impl Display for EHBDecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of input while decoding an Extended Head Byte number"),
+            Self::InvalidHeadByte => f.write_str("reserved follow-up byte count in an Extended Head Byte"),
+            Self::InvalidExponent(e) => Display::fmt(e, f),
+            Self::TrailingBytes => f.write_str("trailing bytes after an Extended Head Byte number"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for EHBDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidExponent(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
-mod ops;
\ No newline at end of file
+/// Marker error type indicating that an Extended Head Byte number could not be converted into a Head Byte one, since its exponent is out of range or it has too many coefficient bytes.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TryFromEHBError;
+impl Display for TryFromEHBError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("Extended Head Byte number does not fit into Head Byte")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromEHBError {}
@@ -0,0 +1,51 @@
+//! Correctly rounded conversion of arbitrarily large numbers into floats.
+
+use crate::Sign;
+
+/// Rounds `top × 2^exp2` into a float with the specified widths of the explicitly stored mantissa and of the exponent, returning the raw bits.
+///
+/// The lowest bit of `top` has to be set if any bits were discarded below it (the "sticky" bit), and `top` has to be wide enough for that bit to be below the rounding position, which is always the case if it has at least 2 more bits than the mantissa of the float does. Rounding is performed to the nearest value, ties to even, including the subnormal range.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // The shifts and range checks keep every value within the range of the type it's cast to.
+fn round(sign: Sign, top: u128, exp2: i64, mantissa_bits: u32, exponent_bits: u32) -> u64 {
+    let bias = (1_i64 << (exponent_bits - 1)) - 1;
+    let sign_bit = (bool::from(sign) as u64) << (mantissa_bits + exponent_bits);
+    if top == 0 {return sign_bit;}
+    let infinity = ((bias as u64 * 2 + 1) << mantissa_bits) | sign_bit;
+    let len = 128 - top.leading_zeros() as i64;
+    let exponent = len - 1 + exp2;
+    if exponent > bias {return infinity;}
+    let min_exponent = 1 - bias;
+    // Subnormal numbers have fewer significant bits, depending on how far below the normal range they are.
+    let keep = mantissa_bits as i64 + 1 - (min_exponent - exponent).max(0);
+    if keep < 0 {return sign_bit;}
+    let drop = len - keep;
+    let mantissa = if drop <= 0 {
+        (top << -drop) as u64
+    } else {
+        let mantissa = top.checked_shr(drop as u32).unwrap_or(0);
+        let remainder = top & (u128::MAX >> (128 - drop));
+        let half = 1_u128 << (drop - 1);
+        let round_up = remainder > half || (remainder == half && mantissa & 1 == 1);
+        (mantissa + round_up as u128) as u64
+    };
+    let result = if exponent < min_exponent {
+        // The exponent field is zero, and a carry out of the mantissa correctly produces the smallest normal number.
+        mantissa
+    } else {
+        // The implicit leading bit of the mantissa is added into the exponent field, which also handles the carry from rounding.
+        (((exponent + bias - 1) as u64) << mantissa_bits) + mantissa
+    };
+    if result >= infinity & !sign_bit {infinity} else {result | sign_bit}
+}
+
+/// Rounds `top × 2^exp2` to the nearest `f64`. See [`round`] for the requirements on `top`.
+#[inline]
+pub(crate) fn to_f64(sign: Sign, top: u128, exp2: i64) -> f64 {
+    f64::from_bits(round(sign, top, exp2, 52, 11))
+}
+/// Rounds `top × 2^exp2` to the nearest `f32`. See [`round`] for the requirements on `top`.
+#[inline]
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // `round` produces a 32-bit pattern when given the `f32` layout.
+pub(crate) fn to_f32(sign: Sign, top: u128, exp2: i64) -> f32 {
+    f32::from_bits(round(sign, top, exp2, 23, 8) as u32)
+}
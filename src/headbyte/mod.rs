@@ -2,11 +2,14 @@
 //!
 //! It's recommended to use this format instead of Extended Head Byte if you're accepting numbers from potentially untrusted locations, since Head Byte imposes a size limit (which is still extremely big, suiting most use cases) while Extended Head Byte does not.
 
-mod ops;
+mod ops; pub use ops::{TryFromFloatError, TryFromIntError, ParseHBNumError, ParseHBNumErrorKind};
 
 use crate::Sign;
 use core::{
-    convert::TryInto,
+    convert::{TryFrom, TryInto},
+    cmp::Ordering,
+    fmt::{self, Formatter, Display},
+    hash::{Hash, Hasher},
 };
 use alloc::vec::Vec;
 
@@ -15,18 +18,42 @@ use alloc::vec::Vec;
 /// See the [module-level documentation][modhb] for more.
 ///
 /// [modhb]: index.html "bigbit::headbyte — the Head Byte format, capable of storing integers and fractions up to ±1.34078079e+281"
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct HBNum {
     hb: HeadByte,
     exponent: Option<Exponent>,
     bytes: Vec<u8>
 }
 impl HBNum {
+    /// The zero value.
+    ///
+    /// This does not allocate memory.
+    pub const ZERO: Self = Self::special(HeadByte::ZERO);
+    /// The NaN (Not-a-Number) value.
+    pub const NAN: Self = Self::special(HeadByte::NAN);
+    /// The **∞** (positive infinity) value.
+    pub const INFINITY: Self = Self::special(HeadByte::INFINITY);
+    /// The **-∞** (negative infinity) value.
+    pub const NEG_INFINITY: Self = Self::special(HeadByte::NEG_INFINITY);
+
+    /// Creates one of the special values, which consist of only the Head Byte.
+    const fn special(hb: HeadByte) -> Self {
+        Self {hb, exponent: None, bytes: Vec::new()}
+    }
     /// Constructs a new `HBNum` from the head byte, exponent and the coefficients.
     ///
     /// The length of the coefficient storage and the presence of the exponent override the value in the head byte. **If the head byte cannot fit the total number of bytes, this call panics**.
+    ///
+    /// If there is neither an exponent nor any coefficients, the head byte is kept as-is, which is how the zero, NaN and infinity special values are constructed.
+    ///
+    /// # Panics
+    /// Panics if there are more than 63 bytes following the head byte, counting both the coefficients and the exponent.
     #[inline]
     pub fn from_raw_parts(mut hb: HeadByte, exponent: Option<Exponent>, coefficient_bytes: Vec<u8>) -> Self {
+        if exponent.is_none() && coefficient_bytes.is_empty() {
+            hb.set_num_bytes(0);
+            return Self {hb, exponent, bytes: coefficient_bytes};
+        }
         let num_bytes: u8 = coefficient_bytes.len()
             .try_into()
             .expect("the number of coefficients is larger than 63")
@@ -49,7 +76,9 @@ impl HBNum {
     pub const fn headbyte(&self) -> HeadByte {
         self.hb
     }
-    /// Returns the exponent, or `None` if it's not used (mainly the case for integers).
+    /// Returns the exponent, or `None` if it's not used (mainly the case for integers, see [`normalize`][0] for the details).
+    ///
+    /// [0]: #method.normalize "normalize — converts the number into its canonical form"
     #[inline(always)]
     pub const fn exponent(&self) -> Option<Exponent> {
         self.exponent
@@ -57,21 +86,155 @@ impl HBNum {
 
     /// Returns an iterator over the coefficients in little endian byte order.
     #[inline(always)]
-    pub fn coefficient_le_iter(&self) -> impl Iterator<Item = u8> + DoubleEndedIterator + '_ {
-        self.coefficients.iter().copied()
+    pub fn coefficient_le_iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
+        self.bytes.iter().copied()
     }
     /// Returns an iterator over the coefficients in big endian byte order.
     #[inline(always)]
-    pub fn coefficient_be_iter(&self) -> impl Iterator<Item = u8> + DoubleEndedIterator + '_ {
+    pub fn coefficient_be_iter(&self) -> impl DoubleEndedIterator<Item = u8> + '_ {
         self.coefficient_le_iter().rev()
     }
+
+    /// Returns the number of bytes the number occupies when encoded, including the Head Byte itself.
+    #[inline(always)]
+    pub const fn encoded_len(&self) -> usize {
+        1 + self.hb.num_bytes() as usize
+    }
+    /// Encodes the number into its byte representation, as specified by the format: the Head Byte, the exponent byte if there is one, and then the coefficients in little endian byte order.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.encoded_len());
+        result.push(self.hb.into_inner());
+        if let Some(exponent) = self.exponent {
+            result.push(exponent.into_inner());
+        }
+        result.extend_from_slice(&self.bytes);
+        result
+    }
+    /// Writes the byte representation of the number into the specified writer.
+    ///
+    /// See [`to_bytes`][0] for the layout.
+    ///
+    /// # Errors
+    /// Any I/O errors returned by the writer are passed through.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into its byte representation"
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes())
+    }
+    /// Decodes a number from the beginning of a byte slice, returning it along with the number of bytes it occupied.
+    ///
+    /// The Head Byte is read first, then the exponent if the Head Byte says that there is one, then exactly as many coefficient bytes as the Head Byte specifies. Any bytes after the number are left alone, which allows parsing several numbers stored back-to-back. For a version which rejects them, see [`from_bytes`][0].
+    ///
+    /// # Errors
+    /// See [`HBDecodeError`][1].
+    ///
+    /// [0]: #method.from_bytes "from_bytes — decodes a number which occupies the entire byte slice"
+    /// [1]: enum.HBDecodeError.html "HBDecodeError — the error type for decoding Head Byte numbers"
+    pub fn parse(op: &[u8]) -> Result<(Self, usize), HBDecodeError> {
+        let (&hb, mut rest) = op.split_first().ok_or(HBDecodeError::UnexpectedEnd)?;
+        let hb = HeadByte::from(hb);
+        let exponent = if hb.has_exponent() {
+            let (&exponent, exponent_rest) = rest.split_first().ok_or(HBDecodeError::UnexpectedEnd)?;
+            rest = exponent_rest;
+            Some(Exponent::try_from(exponent)?)
+        } else {None};
+        let num_coefficients = hb.num_coefficients() as usize;
+        let bytes = rest.get(..num_coefficients).ok_or(HBDecodeError::UnexpectedEnd)?;
+        Ok((Self {hb, exponent, bytes: bytes.to_vec()}, 1 + hb.num_bytes() as usize))
+    }
+    /// Decodes a number which occupies the entire byte slice.
+    ///
+    /// # Errors
+    /// See [`HBDecodeError`][0]. Unlike [`parse`][1], any bytes left over after the number are treated as an error.
+    ///
+    /// [0]: enum.HBDecodeError.html "HBDecodeError — the error type for decoding Head Byte numbers"
+    /// [1]: #method.parse "parse — decodes a number from the beginning of a byte slice"
+    pub fn from_bytes(op: &[u8]) -> Result<Self, HBDecodeError> {
+        let (result, consumed) = Self::parse(op)?;
+        if consumed != op.len() {
+            return Err(HBDecodeError::TrailingBytes);
+        }
+        Ok(result)
+    }
+    /// Decodes a number from the beginning of a byte slice, rejecting it if it's not in the canonical form. This guarantees that every value can only be decoded from one byte sequence, which is important when the encoded numbers are hashed or signed.
+    ///
+    /// Otherwise, this works just like [`parse`][0].
+    ///
+    /// # Errors
+    /// See [`HBDecodeError`][1]. Numbers which aren't in the canonical form, as described in [`normalize`][2], produce `NonCanonical`.
+    ///
+    /// [0]: #method.parse "parse — decodes a number from the beginning of a byte slice"
+    /// [1]: enum.HBDecodeError.html "HBDecodeError — the error type for decoding Head Byte numbers"
+    /// [2]: #method.normalize "normalize — converts the number into its canonical form"
+    pub fn parse_strict(op: &[u8]) -> Result<(Self, usize), HBDecodeError> {
+        let (result, consumed) = Self::parse(op)?;
+        if !result.is_canonical() {
+            return Err(HBDecodeError::NonCanonical);
+        }
+        Ok((result, consumed))
+    }
+    /// Decodes a number which occupies the entire byte slice, rejecting it if it's not in the canonical form.
+    ///
+    /// # Errors
+    /// See [`parse_strict`][0] and [`from_bytes`][1].
+    ///
+    /// [0]: #method.parse_strict "parse_strict — decodes a number in the canonical form from the beginning of a byte slice"
+    /// [1]: #method.from_bytes "from_bytes — decodes a number which occupies the entire byte slice"
+    pub fn from_bytes_strict(op: &[u8]) -> Result<Self, HBDecodeError> {
+        let (result, consumed) = Self::parse_strict(op)?;
+        if consumed != op.len() {
+            return Err(HBDecodeError::TrailingBytes);
+        }
+        Ok(result)
+    }
+}
+
+/// The error type for decoding Head Byte numbers from bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum HBDecodeError {
+    /// The input ended before the entire number could be read, i.e. there were fewer bytes than the Head Byte specified.
+    UnexpectedEnd,
+    /// The exponent byte was `-0`.
+    InvalidExponent(InvalidExponentError),
+    /// There were bytes left over after the number.
+    TrailingBytes,
+    /// The number was not in the canonical form, which is only checked by the strict decoding functions.
+    NonCanonical,
+}
+impl From<InvalidExponentError> for HBDecodeError {
+    #[inline(always)]
+    fn from(op: InvalidExponentError) -> Self {
+        Self::InvalidExponent(op)
+    }
+}
+impl Display for HBDecodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("unexpected end of input while decoding a Head Byte number"),
+            Self::InvalidExponent(e) => Display::fmt(e, f),
+            Self::TrailingBytes => f.write_str("trailing bytes after a Head Byte number"),
+            Self::NonCanonical => f.write_str("Head Byte number is not in the canonical form"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for HBDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidExponent(e) => Some(e),
+            _ => None,
+        }
+    }
 }
 
 /// The Head Byte itself, containing information about the sign, presence of the exponent and the number of coefficients.
 ///
 /// Follows the newtype pattern, meaning that it can be unwrapped into the inner byte.
+///
+/// Comparisons work the same way as for the numbers themselves, as far as a Head Byte alone can tell: NaN is not equal to anything, including itself, the infinities are the largest and the smallest values and the signs of finite numbers are taken into account. Apart from that, two Head Bytes of finite numbers with the same sign are only equal if they're the same byte and are unordered otherwise, since their actual values depend on the exponent and coefficients.
 #[repr(transparent)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Copy, Clone)]
 pub struct HeadByte(u8);
 
 impl HeadByte {
@@ -147,10 +310,10 @@ impl HeadByte {
     /// [0]: #method.set_exponent_bit "set_exponent_bit — sets the exponent bit in the Head Byte"
     #[inline]
     #[must_use = "use set_exponent_bit to perform the operation in-place"]
-    pub fn with_exponent_bit(self, op: bool) -> Self {
+    pub const fn with_exponent_bit(self, op: bool) -> Self {
         Self(match op {
-            true  => self.0 & !Self::HAS_EXPONENT_MASK,
-            false => self.0 |  Self::HAS_EXPONENT_MASK,
+            true  => self.0 |  Self::HAS_EXPONENT_MASK,
+            false => self.0 & !Self::HAS_EXPONENT_MASK,
         })
     }
     /// Sets the exponent presence bit in the Head Byte. If the number of expected bytes which follow the number is zero, setting it to `true` produces the infinity special-case Head Byte.
@@ -173,7 +336,7 @@ impl HeadByte {
     }
     /// Checks whether the Head Byte describes a NaN value.
     #[inline(always)]
-    pub fn is_nan(self) -> bool {
+    pub const fn is_nan(self) -> bool {
         (self.0 & Self::SIGN_MASK) != 0 && (self.0 & Self::ABS_MASK) == 0
     }
     /// Checks whether the Head Byte is supposed to be followed by an exponent byte.
@@ -209,6 +372,9 @@ impl HeadByte {
     ///
     /// The in-place counterpart is [`with_num_bytes`][0].
     ///
+    /// # Panics
+    /// Panics if `op` is larger than 63.
+    ///
     /// [0]: #method.with_num_bytes "with_num_bytes — constructs a Head Byte which has the sign and exponent flags as the one specified but also sets the number of following bytes expected to a new value"
     #[inline]
     #[must_use = "use set_num_bytes to perform the operation in-place"]
@@ -262,12 +428,54 @@ impl HeadByte {
         *self = self.with_num_coefficients(op)
     }
 
+    /// Returns the ordering between two Head Bytes, taking NaN into account.
+    ///
+    /// NaN is considered equal to itself and larger than every other value, including positive infinity. Head Bytes of finite numbers are ordered by their sign first and by the byte itself otherwise, which has nothing to do with their values but makes the ordering total.
+    #[inline]
+    pub fn total_cmp(self, rhs: Self) -> Ordering {
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.rank().cmp(&rhs.rank()).then_with(|| self.0.cmp(&rhs.0)),
+        }
+    }
+    /// Places the value in one of the ranges distinguishable by the Head Byte alone: from -2 for negative infinity to 2 for positive infinity, with 0 for zero. NaN has to be handled separately.
+    pub(crate) fn rank(self) -> i8 {
+        let rank = if self.is_infinite() {2} else {i8::from(self.abs().0 != 0)};
+        match self.sign() {
+            Sign::Positive => rank,
+            Sign::Negative => -rank,
+        }
+    }
     /// Consumes the value and returns the inner byte.
     #[inline(always)]
     pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
+impl PartialEq for HeadByte {
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        !self.is_nan() && self.0 == rhs.0
+    }
+}
+impl PartialOrd for HeadByte {
+    #[inline]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if self.is_nan() || rhs.is_nan() {return None;}
+        match self.rank().cmp(&rhs.rank()) {
+            Ordering::Equal if self.0 != rhs.0 => None,
+            ordering => Some(ordering),
+        }
+    }
+}
+impl Hash for HeadByte {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
 impl From<u8> for HeadByte {
     /// Wraps a byte into a Head Byte.
     #[inline(always)]
@@ -289,7 +497,7 @@ impl core::ops::Neg for HeadByte {
         if self.is_nan() || self == Self::ZERO {
             self
         } else {
-            Self(!(self.0 & Self::SIGN_MASK) | self.abs().0)
+            Self(self.0 ^ Self::SIGN_MASK)
         }
     }
 }
@@ -357,6 +565,18 @@ impl Exponent {
     pub const unsafe fn from_u8_unchecked(op: u8) -> Self {
         Self(op)
     }
+    /// Converts a 2's complement signed integer into an exponent.
+    ///
+    /// This is not a `TryFrom` implementation to keep integer literals passed to `Exponent::try_from` unambiguous.
+    ///
+    /// # Errors
+    /// Since the exponent only ranges from -127 to +127, `-128` produces `Err(InvalidExponentError)`.
+    #[inline]
+    pub const fn from_i8(op: i8) -> Result<Self, InvalidExponentError> {
+        if op == i8::MIN {return Err(InvalidExponentError);}
+        let abs = op.unsigned_abs();
+        Ok(Self(if op < 0 {Self::SIGN_MASK | abs} else {abs}))
+    }
     /// Extracts the sign from the exponent. `Negative` means that the coefficient is multiplied by 10 raised to the power of `-n`, where `n` is the rest of the exponent byte, and `Positive` simply means `10^n`.
     #[inline(always)]
     pub fn sign(self) -> Sign {
@@ -368,12 +588,11 @@ impl Exponent {
     pub const fn abs(self) -> Self {
         Self(self.0 & Self::ABS_MASK)
     }
-    /// Inverts the sign bit of the exponent. `10^2` (`0b0000_0010`) becomes `10^-2` (`0b1000_0010`), `10^127` → `10^-127` and so on.
+    /// Inverts the sign bit of the exponent. `10^2` (`0b0000_0010`) becomes `10^-2` (`0b1000_0010`), `10^127` → `10^-127` and so on. `10^0` stays the same, since `-0` is not a valid exponent.
     #[inline(always)]
     #[must_use = "this is not an in-place operation"]
     pub const fn invert(self) -> Self {
-        let sign = !(self.0 & Self::SIGN_MASK);
-        Self((self.0 & Self::ABS_MASK) | sign)
+        if self.0 == 0 {self} else {Self(self.0 ^ Self::SIGN_MASK)}
     }
     /// Multiplies the exponent with another exponent, returning `None` if the value overflowed.
     ///
@@ -381,29 +600,16 @@ impl Exponent {
     #[inline(always)]
     #[must_use = "this is not an in-place operation"]
     pub fn checked_mul(self, rhs: Self) -> Option<Self> {
-        if rhs.sign() == self.sign() {
-            let sign = self.0 & Self::SIGN_MASK;
-            if let Some(result) = self.abs().0.checked_add(rhs.abs().0) {
-                Some(Self(sign | result))
-            } else {None}
-        } else {
-            self.checked_div(rhs)
-        }
+        i8::from(self).checked_add(i8::from(rhs))
+            .and_then(|result| Self::from_i8(result).ok())
     }
-    /// Divides the exponent by another exponent, returning `None` if the value underflowed.
+    /// Divides the exponent by another exponent, returning `None` if the value overflowed.
     ///
     /// Note how this performs division in the mathematical sense, but **the actual operation is implemented by subtraction and thus is extremely performant**. The struct-level documentation for `Exponent` explains why.
     #[inline(always)]
     #[must_use = "this is not an in-place operation"]
-    fn checked_div(self, rhs: Self) -> Option<Self> {
-        if rhs.sign() == self.sign() {
-            let sign = self.0 & Self::SIGN_MASK;
-            if let Some(result) = self.abs().0.checked_sub(rhs.abs().0) {
-                Some(Self(sign | result))
-            } else {None}
-        } else {
-            self.checked_mul(rhs)
-        }
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.invert())
     }
 
     /// Consumes the value and returns the inner byte.
@@ -414,7 +620,7 @@ impl Exponent {
         self.0
     }
 }
-impl core::convert::TryFrom<u8> for Exponent {
+impl TryFrom<u8> for Exponent {
     type Error = InvalidExponentError;
     /// Wraps a byte into an exponent.
     ///
@@ -435,8 +641,27 @@ impl From<Exponent> for u8 {
         op.0
     }
 }
+impl From<Exponent> for i8 {
+    /// Converts the exponent into a 2's complement signed integer.
+    #[inline]
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_wrap))] // The absolute value of an exponent is at most 127.
+    fn from(op: Exponent) -> Self {
+        let abs = op.abs().0 as Self;
+        match op.sign() {
+            Sign::Positive => abs,
+            Sign::Negative => -abs,
+        }
+    }
+}
 /// The error marker for when `0b10000000` is encountered in the `TryFrom` implementation of [`Exponent`][1].
 ///
 /// [1]: struct.Exponent.html "Exponent — an exponent for the Head Byte format"
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
-pub struct InvalidExponentError;
\ No newline at end of file
+pub struct InvalidExponentError;
+impl Display for InvalidExponentError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid exponent (-0)")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidExponentError {}
\ No newline at end of file
@@ -0,0 +1,50 @@
+use super::{HBNum, HeadByte, Decomposed, Rounding, scale, flip};
+use core::cmp::Ordering;
+
+impl HBNum {
+    /// Adds two numbers, returning `None` if the exact sum can't be stored in the Head Byte format, either because it's too large or because it has too many digits.
+    ///
+    /// Unlike the `+` operator, which rounds a sum with too many digits to the nearest representable number and produces an infinity if the result overflows, this never rounds. NaN and the infinities are handled just like by the operator.
+    #[inline]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.add_impl(rhs, Rounding::Exact).ok()
+    }
+    pub(super) fn add_impl(&self, rhs: &Self, rounding: Rounding) -> Result<Self, Self> {
+        self.add_signed(rhs, false, rounding)
+    }
+    /// Adds `rhs` or, if `negate_rhs` is `true`, subtracts it, returning the infinity of the corresponding sign as the error if the result overflows or can't be rounded as requested.
+    pub(super) fn add_signed(&self, rhs: &Self, negate_rhs: bool, rounding: Rounding) -> Result<Self, Self> {
+        let (Some(lhs), Some(mut rhs)) = (self.decompose(), rhs.decompose()) else {
+            let rhs = if negate_rhs {-rhs.hb} else {rhs.hb};
+            return Ok(add_special(self.hb, rhs));
+        };
+        rhs.sign = flip(rhs.sign, negate_rhs);
+        // Align the exponents by scaling up the coefficient with the larger one.
+        let exponent = lhs.exponent.min(rhs.exponent);
+        let lhs_coefficient = scale(lhs.coefficient, (lhs.exponent - exponent).unsigned_abs());
+        let rhs_coefficient = scale(rhs.coefficient, (rhs.exponent - exponent).unsigned_abs());
+        let (sign, coefficient) = if lhs.sign == rhs.sign {
+            (lhs.sign, lhs_coefficient + rhs_coefficient)
+        } else {
+            match lhs_coefficient.cmp(&rhs_coefficient) {
+                Ordering::Greater => (lhs.sign, lhs_coefficient - rhs_coefficient),
+                Ordering::Less => (rhs.sign, rhs_coefficient - lhs_coefficient),
+                Ordering::Equal => return Ok(Self::ZERO),
+            }
+        };
+        Self::compose(Decomposed {sign, coefficient, exponent}, false, rounding)
+            .ok_or_else(|| Self::infinity(sign))
+    }
+}
+
+/// Adds two Head Bytes of which at least one is NaN or infinity: NaN is contagious, infinities of opposite signs produce NaN and otherwise the infinity wins.
+fn add_special(lhs: HeadByte, rhs: HeadByte) -> HBNum {
+    if lhs.is_nan() || rhs.is_nan() {return HBNum::NAN;}
+    match (lhs.is_infinite(), rhs.is_infinite()) {
+        (true, true) if lhs.sign() != rhs.sign() => HBNum::NAN,
+        (true, _) => HBNum::infinity(lhs.sign()),
+        _ => HBNum::infinity(rhs.sign()),
+    }
+}
+
+impl_binop!(Add add AddAssign add_assign add_impl);
@@ -0,0 +1,38 @@
+use super::{HBNum, Decomposed, Rounding, MAX_COEFFICIENTS, flip};
+use crate::{LBNum, DivRem};
+
+impl HBNum {
+    /// Divides `self` by `rhs`, returning `None` if `rhs` is zero or if the exact quotient can't be stored in the Head Byte format, either because it's too large or because it has too many digits (as is the case for `1 / 3`).
+    ///
+    /// Unlike the `/` operator, which rounds a quotient with too many digits to the nearest representable number and produces an infinity if the result overflows or the divisor is zero (or NaN if both operands are zero), this never rounds. NaN and the infinities are handled just like by the operator.
+    #[inline]
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.div_impl(rhs, Rounding::Exact).ok()
+    }
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap))] // The precision is a few hundred digits at most.
+    pub(super) fn div_impl(&self, rhs: &Self, rounding: Rounding) -> Result<Self, Self> {
+        let sign = flip(self.hb.sign(), rhs.hb.sign().into());
+        let (Some(lhs), Some(rhs)) = (self.decompose(), rhs.decompose()) else {
+            if self.hb.is_nan() || rhs.hb.is_nan() {return Ok(Self::NAN);}
+            return Ok(match (self.hb.is_infinite(), rhs.hb.is_infinite()) {
+                (true, true) => Self::NAN,
+                (true, false) => Self::infinity(sign),
+                _ => Self::ZERO,
+            });
+        };
+        if rhs.coefficient == 0_u8 {
+            return Err(if lhs.coefficient == 0_u8 {Self::NAN} else {Self::infinity(sign)});
+        }
+        if lhs.coefficient == 0_u8 {return Ok(Self::ZERO);}
+        // Scale the dividend so that the quotient has more digits than can be stored, which guarantees that it gets rounded with the remainder taken into account.
+        let digits = |op: &LBNum| op.to_base256_le().len() * 240_824 / 100_000;
+        let precision = (MAX_COEFFICIENTS * 3 + digits(&rhs.coefficient) + 2)
+            .saturating_sub(digits(&lhs.coefficient)) as u32;
+        let (coefficient, remainder) = (lhs.coefficient * LBNum::pow10(precision)).div_rem(&rhs.coefficient);
+        let exponent = lhs.exponent - rhs.exponent - precision as i32;
+        Self::compose(Decomposed {sign, coefficient, exponent}, remainder != 0_u8, rounding)
+            .ok_or_else(|| Self::infinity(sign))
+    }
+}
+
+impl_binop!(Div div DivAssign div_assign div_impl);
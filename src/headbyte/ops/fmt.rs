@@ -0,0 +1,141 @@
+use super::{HBNum, Decomposed};
+use crate::Sign;
+use core::{
+    fmt::{self, Formatter},
+    iter,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+impl HBNum {
+    /// Formats the number using a function which renders its decimal digits and exponent, with trailing zeros already removed, into a string. The formatter's precision is passed to the function as well, while the sign, the width and the fill are handled here.
+    ///
+    /// NaN and infinities are written as `NaN`, `Infinity` and `-Infinity`.
+    fn fmt_with(&self, f: &mut Formatter, render: impl FnOnce(Vec<u8>, i32, Option<usize>) -> String) -> fmt::Result {
+        let Some(Decomposed {sign, coefficient, mut exponent}) = self.decompose() else {
+            return if self.hb.is_nan() {
+                // Floats already know not to put a sign or zero padding on NaN.
+                fmt::Display::fmt(&f64::NAN, f)
+            } else {
+                f.pad_integral(self.hb.sign() == Sign::Positive, "", "Infinity")
+            };
+        };
+        let is_zero = coefficient == 0_u8;
+        let mut digits = coefficient.into_string_with_radix(10).into_bytes();
+        if is_zero {
+            exponent = 0;
+        } else {
+            while digits.last() == Some(&b'0') {
+                digits.pop();
+                exponent += 1;
+            }
+        }
+        let result = render(digits, exponent, f.precision());
+        f.pad_integral(sign == Sign::Positive || is_zero, "", &result)
+    }
+}
+
+/// Renders the digits in full decimal notation, with exactly `precision` digits after the decimal point if it's specified.
+fn render_full(mut digits: Vec<u8>, exponent: i32, precision: Option<usize>) -> String {
+    let mut fraction = exponent.min(0).unsigned_abs() as usize;
+    if exponent > 0 {
+        digits.extend(iter::repeat(b'0').take(exponent.unsigned_abs() as usize));
+    }
+    if digits.len() <= fraction { // Make sure that there's at least one digit before the point.
+        digits.splice(..0, iter::repeat(b'0').take(fraction + 1 - digits.len()));
+    }
+    match precision {
+        Some(precision) if precision < fraction => {
+            let keep = digits.len() - (fraction - precision);
+            round_digits(&mut digits, keep);
+            fraction = precision;
+        },
+        Some(precision) => {
+            digits.extend(iter::repeat(b'0').take(precision - fraction));
+            fraction = precision;
+        },
+        None => {},
+    }
+    let point = digits.len() - fraction;
+    let mut result = String::with_capacity(digits.len() + 1);
+    result.extend(digits[..point].iter().map(|&digit| digit as char));
+    if fraction > 0 {
+        result.push('.');
+        result.extend(digits[point..].iter().map(|&digit| digit as char));
+    }
+    result
+}
+/// Renders the digits in scientific notation, with exactly `precision` digits after the decimal point if it's specified.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap))] // A Head Byte coefficient has a few hundred digits at most.
+fn render_scientific(mut digits: Vec<u8>, exponent: i32, precision: Option<usize>, marker: char) -> String {
+    let mut exponent = exponent + digits.len() as i32 - 1;
+    match precision {
+        Some(precision) if precision + 1 < digits.len() => {
+            let carried = round_digits(&mut digits, precision + 1);
+            if carried {
+                // The carry went all the way through and produced another digit, which is always a zero at the end.
+                digits.pop();
+                exponent += 1;
+            }
+        },
+        Some(precision) => digits.resize(precision + 1, b'0'),
+        None => {},
+    }
+    let mut result = String::with_capacity(digits.len() + 6);
+    result.push(digits[0] as char);
+    if digits.len() > 1 {
+        result.push('.');
+        result.extend(digits[1..].iter().map(|&digit| digit as char));
+    }
+    result.push(marker);
+    result.push_str(&exponent.to_string());
+    result
+}
+/// Keeps the first `keep` ASCII digits, rounding the rest off with ties to even. Returns `true` if rounding up carried into a new leading digit.
+fn round_digits(digits: &mut Vec<u8>, keep: usize) -> bool {
+    let round_up = match digits.get(keep) {
+        None => false,
+        Some(&digit) if digit != b'5' => digit > b'5',
+        Some(_) => digits[keep + 1..].iter().any(|&digit| digit != b'0')
+            || keep > 0 && (digits[keep - 1] - b'0') % 2 == 1,
+    };
+    digits.truncate(keep);
+    if !round_up {return false;}
+    for digit in digits.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            return false;
+        }
+    }
+    digits.insert(0, b'1');
+    true
+}
+
+impl fmt::Display for HBNum {
+    /// Formats the number in full decimal notation, e.g. `1500` or `-0.025`.
+    ///
+    /// The precision, if specified, sets the exact number of digits after the decimal point, rounding the value with ties to even if necessary.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with(f, render_full)
+    }
+}
+impl fmt::LowerExp for HBNum {
+    /// Formats the number in scientific notation, e.g. `1.5e3` or `-2.5e-2`.
+    ///
+    /// The precision, if specified, sets the exact number of digits after the decimal point, rounding the value with ties to even if necessary.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with(f, |digits, exponent, precision| render_scientific(digits, exponent, precision, 'e'))
+    }
+}
+impl fmt::UpperExp for HBNum {
+    /// Formats the number in scientific notation with an uppercase `E`, e.g. `1.5E3` or `-2.5E-2`.
+    ///
+    /// The precision, if specified, sets the exact number of digits after the decimal point, rounding the value with ties to even if necessary.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_with(f, |digits, exponent, precision| render_scientific(digits, exponent, precision, 'E'))
+    }
+}
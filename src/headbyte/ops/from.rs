@@ -0,0 +1,179 @@
+use super::super::{HBNum, HeadByte, Exponent};
+use crate::{LBNum, Sign};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Formatter, Display},
+};
+use alloc::{vec, vec::Vec};
+
+impl HBNum {
+    /// Creates an integer from its sign and absolute value, in the canonical form: the trailing decimal zeros are moved into the exponent only if that makes the number shorter.
+    fn from_integer(sign: Sign, magnitude: u128) -> Self {
+        if magnitude == 0 {return Self::ZERO;}
+        let (mut stripped, mut zeros) = (magnitude, 0_u8);
+        while stripped % 10 == 0 {
+            stripped /= 10;
+            zeros += 1;
+        }
+        let hb = match sign {
+            Sign::Negative => HeadByte::from(HeadByte::SIGN_MASK),
+            Sign::Positive => HeadByte::ZERO,
+        };
+        let (bytes, stripped_bytes) = (trimmed_le_bytes(magnitude), trimmed_le_bytes(stripped));
+        if stripped_bytes.len() + 1 < bytes.len() {
+            // A primitive integer has at most 38 trailing zeros, which is well within the range.
+            Self::from_raw_parts(hb, Some(Exponent(zeros)), stripped_bytes)
+        } else {
+            Self::from_raw_parts(hb, None, bytes)
+        }
+    }
+}
+
+/// Returns the little-endian bytes of a primitive integer without the zeros at the most significant end.
+fn trimmed_le_bytes(op: u128) -> Vec<u8> {
+    let mut bytes = op.to_le_bytes().to_vec();
+    while bytes.last() == Some(&0) {bytes.pop();}
+    bytes
+}
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ident)+) => ($(
+        impl From<$ty> for HBNum {
+            #[inline]
+            fn from(op: $ty) -> Self {
+                Self::from_integer(Sign::Positive, op as u128)
+            }
+        }
+    )+)
+}
+macro_rules! impl_from_signed {
+    ($($ty:ident)+) => ($(
+        impl From<$ty> for HBNum {
+            #[inline]
+            fn from(op: $ty) -> Self {
+                Self::from_integer(Sign::from(op < 0), op.unsigned_abs() as u128)
+            }
+        }
+    )+)
+}
+impl_from_unsigned! {
+    u8 u16 u32 u64 u128 usize
+}
+impl_from_signed! {
+    i8 i16 i32 i64 i128 isize
+}
+
+impl TryFrom<f64> for HBNum {
+    type Error = TryFromFloatError;
+    /// Converts a float into a Head Byte number **exactly**, using the shortest possible coefficient.
+    ///
+    /// Every finite float is a binary fraction, which means that it also has an exact decimal representation. Integers have their trailing decimal zeros moved into the exponent, while fractions get a negative exponent of as many digits as there are after the decimal point. NaN and the infinities are converted into the respective special values, while both positive and negative zero become the zero value.
+    ///
+    /// # Errors
+    /// If the exponent is outside of the ±127 range or the coefficient doesn't fit into the bytes which follow the Head Byte, [`TryFromFloatError`][0] is returned. This is the case for very large and very small numbers, as well as for fractions with more than 127 decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::HBNum;
+    /// # use core::convert::TryFrom;
+    /// let number = HBNum::try_from(0.375).unwrap(); // 375 × 10⁻³
+    /// assert_eq!(number.exponent().unwrap().into_inner(), 0b1_0000011);
+    /// assert_eq!(number.coefficient_le_iter().collect::<Vec<_>>(), [0x77, 0x01]);
+    /// assert!(HBNum::try_from(1e300).is_err());
+    /// ```
+    ///
+    /// [0]: struct.TryFromFloatError.html "TryFromFloatError — marker error type indicating that a float cannot be represented as a Head Byte number"
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // The exponents are range-checked before they're narrowed.
+    fn try_from(op: f64) -> Result<Self, TryFromFloatError> {
+        let special = |hb| Ok(Self::from_raw_parts(hb, None, Vec::new()));
+        if op.is_nan() {return special(HeadByte::NAN);}
+        if op.is_infinite() {
+            return special(if op > 0.0 {HeadByte::INFINITY} else {HeadByte::NEG_INFINITY});
+        }
+        if op == 0.0 {return special(HeadByte::ZERO);}
+
+        let bits = op.to_bits();
+        let biased_exponent = i64::from((bits >> 52) as u16 & 0x7FF);
+        let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | 0x0010_0000_0000_0000, biased_exponent - 1075)
+        };
+        // With an odd mantissa, the exponent of 2 is known exactly, which makes finding the shortest decimal trivial.
+        let shift = mantissa.trailing_zeros();
+        mantissa >>= shift;
+        exponent += shift as i64;
+
+        let (coefficient, decimal_exponent) = if exponent < 0 {
+            // m × 2⁻ⁿ = m × 5ⁿ × 10⁻ⁿ, and since m is odd, the coefficient has no trailing decimal zeros.
+            let places = -exponent;
+            if places > 127 {return Err(TryFromFloatError);}
+            let mut coefficient = LBNum::from(mantissa);
+            let mut remaining = places as u32;
+            while remaining != 0 {
+                // 5²⁷ is the largest power of 5 which fits into u64.
+                let step = remaining.min(27);
+                coefficient *= 5_u64.pow(step);
+                remaining -= step;
+            }
+            (coefficient.to_base256_le(), -places)
+        } else {
+            // m × 2ⁿ has as many trailing decimal zeros as there are factors of both 2 and 5 in it.
+            let mut zeros = 0;
+            while mantissa % 5 == 0 && exponent > 0 {
+                mantissa /= 5;
+                exponent -= 1;
+                zeros += 1;
+            }
+            if zeros > 127 || exponent / 8 >= 63 {return Err(TryFromFloatError);}
+            let mut coefficient = vec![0; (exponent / 8) as usize];
+            coefficient.extend(trimmed_le_bytes((mantissa as u128) << (exponent % 8)));
+            (coefficient, zeros)
+        };
+
+        let exponent = match decimal_exponent {
+            0 => None,
+            // The range has been checked above, and -0 can't happen.
+            e if e < 0 => Some(Exponent(Exponent::SIGN_MASK | (-e) as u8)),
+            e => Some(Exponent(e as u8)),
+        };
+        let max_coefficients = if exponent.is_some() {62} else {63};
+        if coefficient.len() > max_coefficients {return Err(TryFromFloatError);}
+        let hb = match op.is_sign_negative() {
+            true => HeadByte::from(HeadByte::SIGN_MASK),
+            false => HeadByte::ZERO,
+        };
+        let mut result = Self::from_raw_parts(hb, exponent, coefficient);
+        // Integers might be shorter without the exponent byte.
+        if decimal_exponent > 0 {result.normalize();}
+        Ok(result)
+    }
+}
+impl TryFrom<f32> for HBNum {
+    type Error = TryFromFloatError;
+    /// Converts a float into a Head Byte number **exactly**, using the shortest possible coefficient.
+    ///
+    /// See the [`f64` version][0] for the details.
+    ///
+    /// # Errors
+    /// See the [`f64` version][0].
+    ///
+    /// [0]: #impl-TryFrom%3Cf64%3E "TryFrom<f64> for HBNum"
+    #[inline(always)]
+    fn try_from(op: f32) -> Result<Self, TryFromFloatError> {
+        // Widening a float is exact, NaN and infinities included.
+        Self::try_from(op as f64)
+    }
+}
+
+/// Marker error type indicating that a float cannot be represented exactly as a Head Byte number, since either its exponent or its coefficient is too large.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TryFromFloatError;
+impl Display for TryFromFloatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("float cannot be represented exactly as a Head Byte number")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromFloatError {}
@@ -0,0 +1,180 @@
+use super::{HBNum, Decomposed, Rounding, MAX_EXPONENT, max_coefficients};
+use crate::{LBNum, Sign};
+use core::{
+    fmt::{self, Formatter, Display},
+    str::FromStr,
+};
+use alloc::string::String;
+
+/// The largest number of significant decimal digits which can possibly fit into the coefficient: 63 bytes hold up to 152 digits.
+const MAX_DIGITS: usize = 152;
+
+impl FromStr for HBNum {
+    type Err = ParseHBNumError;
+    /// Parses a Head Byte number from a decimal string, exactly.
+    ///
+    /// The syntax is the same as for floats, e.g. `-12.5`, `.5`, `3e-7` or `1.5E+3`, except that underscores (`_`) are allowed anywhere after the first digit of the coefficient or the exponent and are ignored. A leading `+` or `-` is allowed. `inf`, `infinity` and `nan` are accepted in any case, with an optional sign for the infinities.
+    ///
+    /// The number isn't required to be written in the same form as it's stored — trailing zeros are moved into the exponent and vice versa, so `1e200` and `1_500e-129` both parse successfully, but `1e300` is too large to be stored and `1e-128` is too precise.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::HBNum;
+    /// let price: HBNum = "-12.50".parse().unwrap();
+    /// assert_eq!(price.to_string(), "-12.5");
+    /// let tiny: HBNum = "3e-7".parse().unwrap();
+    /// assert_eq!(tiny.to_f64(), 3e-7);
+    /// assert!("inf".parse::<HBNum>().unwrap().headbyte().is_infinite());
+    /// ```
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap))] // The exponent is range-checked before it's narrowed.
+    fn from_str(src: &str) -> Result<Self, ParseHBNumError> {
+        let (sign, start) = match src.as_bytes().first() {
+            Some(b'-') => (Sign::Negative, 1),
+            Some(b'+') => (Sign::Positive, 1),
+            _ => (Sign::Positive, 0),
+        };
+        let body = &src[start..];
+        if body.eq_ignore_ascii_case("inf") || body.eq_ignore_ascii_case("infinity") {
+            return Ok(Self::infinity(sign));
+        }
+        if body.eq_ignore_ascii_case("nan") {return Ok(Self::NAN);}
+
+        // The significant digits, i.e. without leading zeros.
+        let mut digits = String::new();
+        let (mut any_digits, mut seen_point) = (false, false);
+        let mut fraction_len = 0_i64;
+        let mut exponent_start = None;
+        for (position, c) in body.char_indices() {
+            let position = start + position;
+            match c {
+                '0'..='9' => {
+                    any_digits = true;
+                    if c != '0' || !digits.is_empty() {digits.push(c);}
+                    if seen_point {fraction_len += 1;}
+                },
+                '_' if any_digits => {},
+                '.' if !seen_point => seen_point = true,
+                'e' | 'E' if any_digits => {
+                    exponent_start = Some(position + 1);
+                    break;
+                },
+                _ => return Err(ParseHBNumError {kind: ParseHBNumErrorKind::InvalidDigit(c), position}),
+            }
+        }
+        if !any_digits {
+            return Err(ParseHBNumError {kind: ParseHBNumErrorKind::NoDigits, position: src.len()});
+        }
+        let exponent = match exponent_start {
+            Some(exponent_start) => parse_exponent(src, exponent_start)?,
+            None => 0,
+        };
+        let mut exponent = exponent - fraction_len;
+        while digits.ends_with('0') {
+            digits.pop();
+            exponent += 1;
+        }
+        if digits.is_empty() {return Ok(Self::ZERO);}
+
+        // Range errors point at the exponent if there is one, since that's what has to be changed, and at the digits otherwise.
+        let out_of_range = ParseHBNumError {kind: ParseHBNumErrorKind::ExponentOutOfRange, position: exponent_start.unwrap_or(start)};
+        let too_many_digits = ParseHBNumError {kind: ParseHBNumErrorKind::TooManyDigits, position: start};
+        if digits.len() > MAX_DIGITS {return Err(too_many_digits);}
+        if exponent < -(MAX_EXPONENT as i64) || exponent > (MAX_EXPONENT as usize + MAX_DIGITS) as i64 {
+            return Err(out_of_range);
+        }
+        let mut exponent = exponent as i32;
+        let mut coefficient = LBNum::from_str_radix(&digits, 10)
+            .expect("the digits have already been validated");
+        let too_large = exponent > MAX_EXPONENT;
+        if too_large || (1..=2).contains(&exponent) && !fits(&coefficient, exponent) {
+            // Either the exponent has to be brought into range, or the number only fits as an integer without the exponent byte.
+            let target = if too_large {MAX_EXPONENT} else {0};
+            coefficient *= LBNum::pow10((exponent - target).unsigned_abs());
+            exponent = target;
+        }
+        if !fits(&coefficient, exponent) {
+            return Err(if too_large {out_of_range} else {too_many_digits});
+        }
+        Self::compose(Decomposed {sign, coefficient, exponent}, false, Rounding::Nearest).ok_or(out_of_range)
+    }
+}
+
+/// Checks whether the coefficient can be stored along with the exponent without rounding.
+fn fits(coefficient: &LBNum, exponent: i32) -> bool {
+    coefficient.to_base256_le().len() <= max_coefficients(exponent)
+}
+/// Parses the exponent in `src[start..]`, saturating at values which are far out of range anyway.
+fn parse_exponent(src: &str, start: usize) -> Result<i64, ParseHBNumError> {
+    let (negative, start) = match src.as_bytes().get(start) {
+        Some(b'-') => (true, start + 1),
+        Some(b'+') => (false, start + 1),
+        _ => (false, start),
+    };
+    let mut result = 0_i64;
+    let mut any_digits = false;
+    for (position, c) in src[start..].char_indices() {
+        if c == '_' && any_digits {continue;}
+        let digit = c.to_digit(10)
+            .ok_or(ParseHBNumError {kind: ParseHBNumErrorKind::InvalidDigit(c), position: start + position})?;
+        any_digits = true;
+        result = (result * 10 + digit as i64).min(1 << 40);
+    }
+    if !any_digits {
+        return Err(ParseHBNumError {kind: ParseHBNumErrorKind::NoDigits, position: src.len()});
+    }
+    Ok(if negative {-result} else {result})
+}
+
+/// The error type for parsing Head Byte numbers from strings.
+///
+/// Along with the [kind of the error][0], the byte position in the string at which it was encountered is provided.
+///
+/// [0]: enum.ParseHBNumErrorKind.html "ParseHBNumErrorKind — the kind of error encountered while parsing a Head Byte number"
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseHBNumError {
+    pub(crate) kind: ParseHBNumErrorKind,
+    pub(crate) position: usize,
+}
+impl ParseHBNumError {
+    /// Returns the kind of the error.
+    #[inline(always)]
+    pub const fn kind(self) -> ParseHBNumErrorKind {
+        self.kind
+    }
+    /// Returns the byte position in the string at which the error was encountered.
+    ///
+    /// For [`NoDigits`][0], this is the length of the string. For [`ExponentOutOfRange`][1], this is the position of the exponent right after the `e`, or of the first digit if no exponent is written. For [`TooManyDigits`][2], this is the position of the first digit.
+    ///
+    /// [0]: enum.ParseHBNumErrorKind.html#variant.NoDigits "ParseHBNumErrorKind::NoDigits — the coefficient or the exponent didn't contain any digits"
+    /// [1]: enum.ParseHBNumErrorKind.html#variant.ExponentOutOfRange "ParseHBNumErrorKind::ExponentOutOfRange — the number requires an exponent outside of the range from -127 to 127"
+    /// [2]: enum.ParseHBNumErrorKind.html#variant.TooManyDigits "ParseHBNumErrorKind::TooManyDigits — the number has more significant digits than fit into the coefficient bytes"
+    #[inline(always)]
+    pub const fn position(self) -> usize {
+        self.position
+    }
+}
+impl Display for ParseHBNumError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            ParseHBNumErrorKind::NoDigits => f.write_str("cannot parse Head Byte number from string without digits"),
+            ParseHBNumErrorKind::InvalidDigit(c) => write!(f, "invalid character {:?} found in string at position {}", c, self.position),
+            ParseHBNumErrorKind::ExponentOutOfRange => f.write_str("the number requires an exponent outside of the range from -127 to 127"),
+            ParseHBNumErrorKind::TooManyDigits => f.write_str("the number has too many digits to fit into 63 coefficient bytes"),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseHBNumError {}
+
+/// The kind of error encountered while parsing a Head Byte number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseHBNumErrorKind {
+    /// The coefficient or the exponent didn't contain any digits, e.g. the string was empty or ended right after the `e`.
+    NoDigits,
+    /// The string contained a character which doesn't belong to a number at that place. The character is included.
+    InvalidDigit(char),
+    /// The number is either too large to be stored in the Head Byte format or is so small (or has so many digits after the decimal point) that it would require an exponent below -127.
+    ExponentOutOfRange,
+    /// The number has more significant digits than fit into the coefficient bytes.
+    TooManyDigits,
+}
@@ -1,4 +1,285 @@
+use super::{HBNum, HeadByte, Exponent};
+use crate::{LBNum, Sign, DivRem};
+use core::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+use tryinto::Decomposed;
+
+/// Implements an arithmetic operator along with its assigning counterpart for all combinations of owned and borrowed operands, using a function which returns either the result rounded to the nearest representable number or the IEEE-like fallback value if the result cannot be represented.
+macro_rules! impl_binop {
+    ($tr:ident $method:ident $tr_assign:ident $method_assign:ident $imp:ident) => {
+        impl<'l, 'r> core::ops::$tr<&'r HBNum> for &'l HBNum {
+            type Output = HBNum;
+            #[inline]
+            fn $method(self, rhs: &'r HBNum) -> HBNum {
+                HBNum::$imp(self, rhs, super::Rounding::Nearest).unwrap_or_else(|fallback| fallback)
+            }
+        }
+        impl<'l> core::ops::$tr<HBNum> for &'l HBNum {
+            type Output = HBNum;
+            #[inline(always)]
+            fn $method(self, rhs: HBNum) -> HBNum {
+                core::ops::$tr::$method(self, &rhs)
+            }
+        }
+        impl<'r> core::ops::$tr<&'r HBNum> for HBNum {
+            type Output = Self;
+            #[inline(always)]
+            fn $method(self, rhs: &'r Self) -> Self {
+                core::ops::$tr::$method(&self, rhs)
+            }
+        }
+        impl core::ops::$tr<HBNum> for HBNum {
+            type Output = Self;
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                core::ops::$tr::$method(&self, &rhs)
+            }
+        }
+        impl<'r> core::ops::$tr_assign<&'r HBNum> for HBNum {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: &'r Self) {
+                *self = core::ops::$tr::$method(&*self, rhs);
+            }
+        }
+        impl core::ops::$tr_assign<HBNum> for HBNum {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: Self) {
+                *self = core::ops::$tr::$method(&*self, &rhs);
+            }
+        }
+    };
+}
+
 mod add;
 mod sub;
 mod mul;
-mod div;
\ No newline at end of file
+mod div;
+mod fmt;
+mod from;
+mod fromstr;
+pub(crate) mod tryinto;
+#[cfg(feature = "num_traits")]
+mod num;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "rand")]
+mod rand;
+pub use from::TryFromFloatError;
+pub use fromstr::{ParseHBNumError, ParseHBNumErrorKind};
+pub use tryinto::TryFromIntError;
+
+/// The largest absolute value of an exponent.
+const MAX_EXPONENT: i32 = 127;
+/// What to do with a value which has too many digits to be stored in the Head Byte format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Rounding {
+    /// Round to the nearest representable number, with ties rounded to even.
+    Nearest,
+    /// Fail instead of discarding any nonzero digits.
+    Exact,
+}
+/// The largest number of coefficient bytes, which is the case when there's no exponent byte.
+const MAX_COEFFICIENTS: usize = HeadByte::NUM_COEFFICIENTS_MASK as usize;
+
+impl HBNum {
+    /// Creates an infinity with the specified sign.
+    pub(crate) const fn infinity(sign: Sign) -> Self {
+        match sign {
+            Sign::Positive => Self::INFINITY,
+            Sign::Negative => Self::NEG_INFINITY,
+        }
+    }
+    /// Builds a finite number from its sign, coefficient and exponent, rounding ties to even if the coefficient has too many digits or the exponent is too small. `inexact` marks that the actual value is slightly larger than the one specified, which is taken into account when rounding.
+    ///
+    /// The result is always in the canonical form, as described in [`normalize`](#method.normalize).
+    ///
+    /// Returns `None` if the number is too large to be stored in the Head Byte format, or if it would have to be rounded and `rounding` is `Rounding::Exact`.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss))] // Exponents and digit counts are bounded by the Head Byte limits and are only cast once they're known to be non-negative.
+    pub(crate) fn compose(value: Decomposed, inexact: bool, rounding: Rounding) -> Option<Self> {
+        if inexact && rounding == Rounding::Exact {return None;}
+        let Decomposed {sign, mut coefficient, mut exponent} = value;
+        if coefficient == 0_u8 && !inexact {return Some(Self::ZERO);}
+        if exponent > MAX_EXPONENT {
+            coefficient *= LBNum::pow10((exponent - MAX_EXPONENT).unsigned_abs());
+            exponent = MAX_EXPONENT;
+        }
+        let num_bytes = coefficient.to_base256_le().len();
+        if exponent < -MAX_EXPONENT || num_bytes > max_coefficients(exponent) {
+            // Start with a lower bound of the number of digits to round off and go up from there.
+            let excess_bytes = num_bytes.saturating_sub(MAX_COEFFICIENTS + 1);
+            let mut digits = ((excess_bytes * 240_824 / 100_000) as u32)
+                .max((-MAX_EXPONENT - exponent).max(0) as u32);
+            loop {
+                let (rounded, lossless) = round_off(&coefficient, digits, inexact);
+                if !lossless && rounding == Rounding::Exact {return None;}
+                let rounded_exponent = exponent + digits as i32;
+                if rounded_exponent > MAX_EXPONENT {return None;}
+                if rounded.to_base256_le().len() <= max_coefficients(rounded_exponent) {
+                    coefficient = rounded;
+                    exponent = rounded_exponent;
+                    break;
+                }
+                digits += 1;
+            }
+        }
+        let mut value = Decomposed {sign, coefficient, exponent};
+        value.strip_trailing_zeros(MAX_EXPONENT);
+        if value.exponent > 0 {
+            // Integers only get the exponent byte if it actually saves space. This also covers integers which need all 63 bytes, since those don't leave any room for it.
+            let integer = scale(value.coefficient.clone(), value.exponent.unsigned_abs());
+            let integer_len = integer.to_base256_le().len();
+            if integer_len <= MAX_COEFFICIENTS && integer_len <= value.coefficient.to_base256_le().len() + 1 {
+                value.coefficient = integer;
+                value.exponent = 0;
+            }
+        }
+        let Decomposed {coefficient, exponent, ..} = value;
+        if coefficient == 0_u8 {return Some(Self::ZERO);}
+        let hb = match sign {
+            Sign::Positive => HeadByte::ZERO,
+            Sign::Negative => HeadByte::from(HeadByte::SIGN_MASK),
+        };
+        let exponent = match exponent {
+            0 => None,
+            exponent => Some(Exponent::from_i8(exponent as i8).ok()?),
+        };
+        Some(Self::from_raw_parts(hb, exponent, coefficient.to_base256_le()))
+    }
+
+    /// Converts the number into its canonical form, without changing its value.
+    ///
+    /// The same value can be stored in several ways, for example with zeros at the most significant end of the coefficient, with trailing decimal zeros in the coefficient instead of a larger exponent, or as zero with a sign or an exponent. Out of those, the canonical form is the shortest one:
+    /// - trailing decimal zeros are moved from the coefficient into the exponent, as far as the exponent allows;
+    /// - however, integers are stored without the exponent byte, unless using it actually makes the number shorter;
+    /// - the coefficient has no zero bytes at the most significant end;
+    /// - zero is always stored as a single positive Head Byte.
+    ///
+    /// NaN and the infinities only have one representation each, so they're always canonical. Every number produced by arithmetic, conversions and parsing is already in the canonical form, which means that equal values always have the same byte representation.
+    #[cfg_attr(feature = "clippy", allow(clippy::missing_panics_doc))] // Rescaling a value which already fits never overflows.
+    pub fn normalize(&mut self) {
+        if let Some(value) = self.decompose() {
+            // Rescaling an exact value which is already stored in the Head Byte format never overflows.
+            *self = Self::compose(value, false, Rounding::Nearest).expect("the canonical form does not fit");
+        }
+    }
+    /// Returns `true` if the number is in its canonical form, as described in [`normalize`][0].
+    ///
+    /// [0]: #method.normalize "normalize — converts the number into its canonical form"
+    pub fn is_canonical(&self) -> bool {
+        let mut normalized = self.clone();
+        normalized.normalize();
+        normalized.to_bytes() == self.to_bytes()
+    }
+}
+
+impl HBNum {
+    /// Returns the ordering between two numbers, taking NaN into account.
+    ///
+    /// NaN is considered equal to itself and larger than every other value, including positive infinity. Otherwise, this is the same as `partial_cmp`, meaning that numbers with the same value are equal regardless of how they're stored.
+    #[inline]
+    pub fn total_cmp(&self, rhs: &Self) -> Ordering {
+        match (self.hb.is_nan(), rhs.hb.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => self.partial_cmp(rhs).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+impl PartialEq for HBNum {
+    /// Checks whether two numbers have the same value, even if they're stored with different exponents, e.g. `1.0e1 == 10`.
+    ///
+    /// NaN is not equal to anything, including itself.
+    #[inline]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.partial_cmp(rhs) == Some(Ordering::Equal)
+    }
+}
+impl PartialOrd for HBNum {
+    /// Compares the values of two numbers, returning `None` if either of them is NaN.
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if self.hb.is_nan() || rhs.hb.is_nan() {return None;}
+        let (Some(lhs), Some(rhs)) = (self.decompose(), rhs.decompose()) else {
+            // At least one is infinite, and the rank of a finite number is always between the ones of the infinities.
+            return Some(self.hb.rank().cmp(&rhs.hb.rank()));
+        };
+        Some(cmp_finite(lhs, rhs))
+    }
+}
+impl Hash for HBNum {
+    /// Hashes the value of the number rather than its representation, so that numbers which are equal always have the same hash.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.decompose() {
+            Some(value) if value.coefficient == 0_u8 => HeadByte::ZERO.hash(state),
+            Some(mut value) => {
+                value.strip_trailing_zeros(i32::MAX);
+                bool::from(value.sign).hash(state);
+                value.coefficient.to_base256_le().hash(state);
+                value.exponent.hash(state);
+            },
+            None => self.hb.hash(state),
+        }
+    }
+}
+
+/// Compares the values of two finite numbers.
+fn cmp_finite(lhs: Decomposed, rhs: Decomposed) -> Ordering {
+    let signum = |op: &Decomposed| if op.coefficient == 0_u8 {0} else {
+        match op.sign {
+            Sign::Positive => 1,
+            Sign::Negative => -1,
+        }
+    };
+    let (lhs_signum, rhs_signum) = (signum(&lhs), signum(&rhs));
+    if lhs_signum != rhs_signum || lhs_signum == 0 {return lhs_signum.cmp(&rhs_signum);}
+    let exponent = lhs.exponent.min(rhs.exponent);
+    let magnitudes = scale(lhs.coefficient, (lhs.exponent - exponent).unsigned_abs())
+        .cmp(&scale(rhs.coefficient, (rhs.exponent - exponent).unsigned_abs()));
+    if lhs_signum < 0 {magnitudes.reverse()} else {magnitudes}
+}
+/// Returns the maximum number of coefficient bytes for a number with the specified exponent.
+const fn max_coefficients(exponent: i32) -> usize {
+    if exponent == 0 {MAX_COEFFICIENTS} else {MAX_COEFFICIENTS - 1}
+}
+/// Multiplies a coefficient by `10^digits`, skipping the multiplication entirely if there's nothing to scale by.
+fn scale(coefficient: LBNum, digits: u32) -> LBNum {
+    if digits == 0 {coefficient} else {coefficient * LBNum::pow10(digits)}
+}
+/// Divides a coefficient by `10^digits`, rounding ties to even. `inexact` marks that the actual value is slightly larger than the coefficient. The flag returned alongside the quotient tells whether nothing was lost.
+fn round_off(coefficient: &LBNum, digits: u32, inexact: bool) -> (LBNum, bool) {
+    if digits == 0 {return (coefficient.clone(), true);}
+    let divisor = LBNum::pow10(digits);
+    let (mut quotient, remainder) = coefficient.clone().div_rem(&divisor);
+    let lossless = remainder == 0_u8 && !inexact;
+    let round_up = match (remainder * 2_u8).cmp(&divisor) {
+        core::cmp::Ordering::Greater => true,
+        core::cmp::Ordering::Equal => inexact || quotient.digits().next().is_some_and(|digit| digit & 1 == 1),
+        core::cmp::Ordering::Less => false,
+    };
+    if round_up {quotient.increment();}
+    (quotient, lossless)
+}
+/// Flips the sign if `op` is `true`.
+fn flip(sign: Sign, op: bool) -> Sign {
+    Sign::from(bool::from(sign) ^ op)
+}
+
+impl core::ops::Neg for HBNum {
+    type Output = Self;
+    /// Flips the sign of the number. NaN and zero stay the same.
+    #[inline]
+    fn neg(mut self) -> Self {
+        self.hb = -self.hb;
+        self
+    }
+}
+impl core::ops::Neg for &HBNum {
+    type Output = HBNum;
+    /// Flips the sign of the number. NaN and zero stay the same.
+    #[inline]
+    fn neg(self) -> HBNum {
+        -self.clone()
+    }
+}
@@ -0,0 +1,32 @@
+use super::{HBNum, Decomposed, Rounding, flip};
+
+impl HBNum {
+    /// Multiplies two numbers, returning `None` if the exact product can't be stored in the Head Byte format, either because it's too large or because it has too many digits.
+    ///
+    /// Unlike the `*` operator, which rounds a product with too many digits to the nearest representable number and produces an infinity if the result overflows, this never rounds. NaN and the infinities are handled just like by the operator.
+    #[inline]
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        self.mul_impl(rhs, Rounding::Exact).ok()
+    }
+    pub(super) fn mul_impl(&self, rhs: &Self, rounding: Rounding) -> Result<Self, Self> {
+        let sign = flip(self.hb.sign(), rhs.hb.sign().into());
+        let (Some(lhs_parts), Some(rhs_parts)) = (self.decompose(), rhs.decompose()) else {
+            if self.hb.is_nan() || rhs.hb.is_nan() {return Ok(Self::NAN);}
+            // Zero times infinity is undefined, everything else just picks up the sign.
+            let zero = |op: &Self| op.decompose().is_some_and(|op| op.coefficient == 0_u8);
+            return Ok(if zero(self) || zero(rhs) {Self::NAN} else {Self::infinity(sign)});
+        };
+        // Exponents are multiplied by adding them, which Exponent::checked_mul does as long as both numbers have an exponent byte and the result fits into one.
+        let exponent = match (self.exponent, rhs.exponent) {
+            (Some(lhs), Some(rhs)) => lhs.checked_mul(rhs).map(|exponent| i32::from(i8::from(exponent))),
+            _ => None,
+        };
+        // Otherwise, the exponent is either implicitly zero on one side or out of range, and the plain sum is used instead. An out-of-range sum gets absorbed by the coefficient while composing.
+        let exponent = exponent.unwrap_or(lhs_parts.exponent + rhs_parts.exponent);
+        let coefficient = lhs_parts.coefficient * rhs_parts.coefficient;
+        Self::compose(Decomposed {sign, coefficient, exponent}, false, rounding)
+            .ok_or_else(|| Self::infinity(sign))
+    }
+}
+
+impl_binop!(Mul mul MulAssign mul_assign mul_impl);
@@ -0,0 +1,109 @@
+//! Implementations of the traits from `num-traits`.
+
+use super::super::HBNum;
+use core::convert::TryFrom;
+use num_traits::{
+    Zero, One,
+    CheckedAdd, CheckedSub, CheckedMul, CheckedDiv,
+    ToPrimitive, FromPrimitive,
+};
+
+impl Zero for HBNum {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    /// Checks whether the number is zero, including zeros which are stored with coefficient bytes which are all zero.
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.decompose().is_some_and(|op| op.coefficient == 0_u8)
+    }
+}
+impl One for HBNum {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::from(1_u8)
+    }
+}
+
+impl CheckedAdd for HBNum {
+    #[inline(always)]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+}
+impl CheckedSub for HBNum {
+    #[inline(always)]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+}
+impl CheckedMul for HBNum {
+    #[inline(always)]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+}
+impl CheckedDiv for HBNum {
+    #[inline(always)]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+}
+
+impl ToPrimitive for HBNum {
+    #[inline(always)]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+    /// Converts the number to the nearest `f64`. This never fails.
+    #[inline(always)]
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.to_f64())
+    }
+    /// Converts the number to the nearest `f32`. This never fails.
+    #[inline(always)]
+    fn to_f32(&self) -> Option<f32> {
+        Some(self.to_f32())
+    }
+}
+impl FromPrimitive for HBNum {
+    #[inline(always)]
+    fn from_i64(op: i64) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    #[inline(always)]
+    fn from_u64(op: u64) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    #[inline(always)]
+    fn from_i128(op: i128) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    #[inline(always)]
+    fn from_u128(op: u128) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    /// Converts the float into the exact Head Byte number, failing if it's not representable exactly.
+    #[inline(always)]
+    fn from_f64(op: f64) -> Option<Self> {
+        Self::try_from(op).ok()
+    }
+    /// Converts the float into the exact Head Byte number, failing if it's not representable exactly.
+    #[inline(always)]
+    fn from_f32(op: f32) -> Option<Self> {
+        Self::try_from(op).ok()
+    }
+}
@@ -0,0 +1,36 @@
+//! Implementations of the `Standard` distribution from `rand`.
+//!
+//! Every valid value can be produced, including NaN, the infinities and numbers which aren't in the canonical form, which makes these useful for fuzzing and property testing.
+
+use crate::headbyte::{HBNum, HeadByte, Exponent};
+use alloc::vec;
+use rand::{Rng, distributions::{Distribution, Standard}};
+
+impl Distribution<HeadByte> for Standard {
+    /// Samples a Head Byte uniformly, since every byte is a valid one.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HeadByte {
+        HeadByte::from(rng.gen::<u8>())
+    }
+}
+impl Distribution<Exponent> for Standard {
+    /// Samples an exponent uniformly from the range from -127 to 127.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Exponent {
+        Exponent::from_i8(rng.gen_range(-127..=127)).expect("the range excludes -128")
+    }
+}
+impl Distribution<HBNum> for Standard {
+    /// Samples a Head Byte uniformly, followed by a uniformly distributed exponent if it specifies one and uniformly distributed coefficient bytes.
+    ///
+    /// As a result, each of zero, NaN, positive infinity and negative infinity is produced with a probability of 1/256. Use [`normalize`][0] on the result to get the canonical form.
+    ///
+    /// [0]: ../struct.HBNum.html#method.normalize "normalize — converts the number into its canonical form"
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> HBNum {
+        let hb: HeadByte = self.sample(rng);
+        let exponent = if hb.has_exponent() {Some(self.sample(rng))} else {None};
+        let mut coefficients = vec![0_u8; hb.num_coefficients() as usize];
+        rng.fill_bytes(&mut coefficients);
+        HBNum::from_raw_parts(hb, exponent, coefficients)
+    }
+}
@@ -0,0 +1,95 @@
+//! Implementations of the `Serialize` and `Deserialize` traits from `serde`.
+//!
+//! Human-readable formats get the number as a decimal string, while binary formats get the Head Byte encoding as a byte array. The Head Byte and the exponent are serialized as plain integers in both cases.
+
+use crate::{headbyte::{HBNum, HeadByte, Exponent}, serde_bytes::visit_byte_seq};
+use core::{convert::TryFrom, fmt::{self, Formatter}};
+use serde::{
+    Serialize, Serializer, Deserialize, Deserializer,
+    de::{self, Visitor, SeqAccess, Unexpected},
+};
+
+impl Serialize for HBNum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+impl<'de> Deserialize<'de> for HBNum {
+    /// Deserializes a number from a decimal string (or a primitive number) in human-readable formats and from its Head Byte encoding otherwise.
+    ///
+    /// The encoding doesn't have to be in the canonical form, but it has to occupy the entire byte array.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            // Self-describing formats may store the number as a primitive number rather than a string.
+            deserializer.deserialize_any(HBNumVisitor)
+        } else {
+            deserializer.deserialize_bytes(HBNumVisitor)
+        }
+    }
+}
+
+struct HBNumVisitor;
+impl<'de> Visitor<'de> for HBNumVisitor {
+    type Value = HBNum;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a Head Byte number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<HBNum, E> {
+        v.parse().map_err(E::custom)
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<HBNum, E> {
+        Ok(HBNum::from(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<HBNum, E> {
+        Ok(HBNum::from(v))
+    }
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<HBNum, E> {
+        Ok(HBNum::from(v))
+    }
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<HBNum, E> {
+        Ok(HBNum::from(v))
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<HBNum, E> {
+        HBNum::try_from(v).map_err(E::custom)
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<HBNum, E> {
+        HBNum::from_bytes(v).map_err(|_| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<HBNum, A::Error> {
+        self.visit_bytes(&visit_byte_seq(seq)?)
+    }
+}
+
+impl Serialize for HeadByte {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(self.into_inner())
+    }
+}
+impl<'de> Deserialize<'de> for HeadByte {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u8::deserialize(deserializer).map(Self::from)
+    }
+}
+
+impl Serialize for Exponent {
+    /// Serializes the exponent as its actual value rather than the sign-magnitude byte.
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i8(i8::from(*self))
+    }
+}
+impl<'de> Deserialize<'de> for Exponent {
+    /// Deserializes the exponent from its actual value, which has to be in the range from -127 to 127.
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = i8::deserialize(deserializer)?;
+        Self::from_i8(value).map_err(|_| de::Error::invalid_value(Unexpected::Signed(value as i64), &"an exponent from -127 to 127"))
+    }
+}
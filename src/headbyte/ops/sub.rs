@@ -0,0 +1,16 @@
+use super::{HBNum, Rounding};
+
+impl HBNum {
+    /// Subtracts `rhs` from `self`, returning `None` if the exact difference can't be stored in the Head Byte format, either because it's too large or because it has too many digits.
+    ///
+    /// Unlike the `-` operator, which rounds a difference with too many digits to the nearest representable number and produces an infinity if the result overflows, this never rounds. NaN and the infinities are handled just like by the operator.
+    #[inline]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.sub_impl(rhs, Rounding::Exact).ok()
+    }
+    pub(super) fn sub_impl(&self, rhs: &Self, rounding: Rounding) -> Result<Self, Self> {
+        self.add_signed(rhs, true, rounding)
+    }
+}
+
+impl_binop!(Sub sub SubAssign sub_assign sub_impl);
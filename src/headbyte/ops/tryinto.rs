@@ -0,0 +1,148 @@
+use super::super::HBNum;
+use crate::{LBNum, LBInt, Sign, DivRem, float};
+use core::{
+    convert::TryFrom,
+    fmt::{self, Formatter, Display},
+};
+
+/// The finite value of a Head Byte number, as a sign, a coefficient and a decimal exponent.
+pub(crate) struct Decomposed {
+    pub(crate) sign: Sign,
+    pub(crate) coefficient: LBNum,
+    pub(crate) exponent: i32,
+}
+
+impl Decomposed {
+    /// Removes trailing zeros from the coefficient by increasing the exponent, stopping once it reaches `max_exponent`.
+    pub(crate) fn strip_trailing_zeros(&mut self, max_exponent: i32) {
+        while self.exponent < max_exponent && self.coefficient != 0_u8 {
+            let (quotient, remainder) = self.coefficient.clone().div_rem(10_u8);
+            if remainder != 0_u8 {break;}
+            self.coefficient = quotient;
+            self.exponent += 1;
+        }
+    }
+}
+
+impl HBNum {
+    /// Splits a finite number into its sign, coefficient and exponent, or returns `None` for NaN and infinities.
+    pub(crate) fn decompose(&self) -> Option<Decomposed> {
+        if self.hb.is_nan() || self.hb.is_infinite() {return None;}
+        let exponent = self.exponent.map_or(0, |exponent| i8::from(exponent) as i32);
+        Some(Decomposed {
+            sign: self.hb.sign(),
+            coefficient: LBNum::from_base256_le(&self.bytes),
+            exponent,
+        })
+    }
+    /// Converts the number into an integer, failing if it's not finite or has a fractional part.
+    fn to_lbint(&self) -> Result<LBInt, TryFromIntError> {
+        let Decomposed {sign, coefficient, exponent} = self.decompose().ok_or(TryFromIntError)?;
+        let magnitude = if exponent >= 0 {
+            coefficient * LBNum::pow10(exponent.unsigned_abs())
+        } else {
+            let (quotient, remainder) = coefficient.div_rem(LBNum::pow10(exponent.unsigned_abs()));
+            if remainder != 0_u8 {return Err(TryFromIntError);}
+            quotient
+        };
+        Ok(LBInt::new(sign, magnitude))
+    }
+    /// Converts the number into the top bits of its absolute value and a binary exponent, as expected by the float rounding functions.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_wrap))] // The shift is a bit count of a Head Byte coefficient.
+    fn to_binary(&self) -> Result<(Sign, u128, i64), f64> {
+        if self.hb.is_nan() {return Err(f64::NAN);}
+        if self.hb.is_infinite() {
+            return Err(match self.hb.sign() {
+                Sign::Positive => f64::INFINITY,
+                Sign::Negative => f64::NEG_INFINITY,
+            });
+        }
+        let Decomposed {sign, coefficient, exponent} = self.decompose().ok_or(f64::NAN)?;
+        if exponent >= 0 {
+            let (top, exp2) = (coefficient * LBNum::pow10(exponent.unsigned_abs())).top_bits();
+            return Ok((sign, top, exp2));
+        }
+        if coefficient == 0_u8 {return Ok((sign, 0, 0));}
+        // Divide with enough extra binary places for the quotient to have well over 66 significant bits, then fold the remainder into the sticky bit.
+        let divisor = LBNum::pow10(exponent.unsigned_abs());
+        let shift = (divisor.num_bytes() * 7 + 66).saturating_sub((coefficient.num_bytes() - 1) * 7);
+        let mut shifted = alloc::vec![0_u8; shift / 8];
+        shifted.push(1 << (shift % 8));
+        let (quotient, remainder) = (coefficient * LBNum::from_base256_le(&shifted)).div_rem(divisor);
+        let (mut top, exp2) = quotient.top_bits();
+        if remainder != 0_u8 {top |= 1;}
+        Ok((sign, top, exp2 - shift as i64))
+    }
+    /// Converts the number into the nearest `f64`, rounding ties to even.
+    ///
+    /// NaN and the infinities are converted into their `f64` counterparts. Every finite Head Byte number is well within the range of `f64`, so this never produces infinities or subnormal numbers otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::{HBNum, HeadByte, headbyte::Exponent};
+    /// # use core::convert::TryFrom;
+    /// // 1 × 10⁻¹ — exactly the decimal 0.1, which rounds to the same f64 as the literal.
+    /// let tenth = HBNum::from_raw_parts(HeadByte::ZERO, Some(Exponent::try_from(0b1_0000001).unwrap()), vec![1]);
+    /// assert_eq!(tenth.to_f64(), 0.1);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        match self.to_binary() {
+            Ok((sign, top, exp2)) => float::to_f64(sign, top, exp2),
+            Err(special) => special,
+        }
+    }
+    /// Converts the number into the nearest `f32`, rounding ties to even.
+    ///
+    /// Unlike with [`to_f64`][0], numbers outside of the range of `f32` are converted into infinities, zero or subnormal numbers, as appropriate. The result is rounded only once, i.e. this is **not** the same as `to_f64() as f32`.
+    ///
+    /// [0]: #method.to_f64 "to_f64 — converts the number into the nearest f64"
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // The special values are NaN and the infinities, which f32 represents exactly.
+    pub fn to_f32(&self) -> f32 {
+        match self.to_binary() {
+            Ok((sign, top, exp2)) => float::to_f32(sign, top, exp2),
+            Err(special) => special as f32,
+        }
+    }
+}
+
+macro_rules! impl_from_hb_for_primitive {
+    ($($ty:ident)+) => ($(
+        impl TryFrom<&HBNum> for $ty {
+            type Error = TryFromIntError;
+            /// Converts a Head Byte number into a primitive integer.
+            ///
+            /// # Errors
+            /// Fails if the number is NaN, infinite, has a fractional part or doesn't fit into the primitive type.
+            #[inline]
+            fn try_from(op: &HBNum) -> Result<Self, TryFromIntError> {
+                $ty::try_from(&op.to_lbint()?).map_err(|_| TryFromIntError)
+            }
+        }
+        impl TryFrom<HBNum> for $ty {
+            type Error = TryFromIntError;
+            #[inline(always)]
+            fn try_from(op: HBNum) -> Result<Self, TryFromIntError> {
+                $ty::try_from(&op)
+            }
+        }
+    )+)
+}
+impl_from_hb_for_primitive! {
+    u8      i8
+    u16     i16
+    u32     i32
+    u64     i64
+    u128   i128
+    usize isize
+}
+
+/// Marker error type indicating that an integer conversion from a Head Byte number has failed, either because the number is NaN, infinite or fractional or because it doesn't fit into the integer type.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TryFromIntError;
+impl Display for TryFromIntError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("conversion from Head Byte to scalar integer failed")
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
@@ -9,24 +9,32 @@
 //!
 //! # State
 //! Currently, not the entire BigBit standard is implemented, and **the crate is not ready for use in production just yet**. There are also **no stability guarantees whatsoever**. Here's a list of what's already done:
-//! - Head Byte number storage (not really finished, just a stub)
-//! - Linked Bytes number storage and arithmetic
+//! - Head Byte number storage, including encoding into and decoding from bytes
+//! - Converting Head Byte numbers to and from primitive integers and `f32`/`f64`
+//! - Head Byte arithmetic (addition, subtraction, multiplication and division), with correct rounding and IEEE 754-like handling of NaN and infinities
+//! - Displaying Head Byte numbers in full decimal notation and scientific notation, with support for formatter precision, and parsing them from decimal strings without losing precision
+//! - Canonical forms for Head Byte and Linked Bytes numbers, with strict decoding which rejects non-canonical input, making the encoding deterministic
+//! - Extended Head Byte number storage, including encoding into and decoding from bytes
+//! - Linked Bytes number storage and arithmetic, including bitwise operations, bit shifts, exponentiation, modular exponentiation, integer logarithms and roots
+//! - Primality testing (deterministic below 2⁶⁴, Baillie–PSW above) and prime generation for Linked Bytes numbers, with randomness supplied by the caller
+//! - Unicode strings encoded using Linked Bytes, with editing, searching, splitting and slicing performed on the encoding directly
+//! - Signed Linked Bytes integers, stored as a sign and an absolute value and encoded using the zigzag encoding
 //! - Converting Linked Bytes to and from primitive integers
 //! - Displaying Linked Bytes numbers in binary, octal, decimal and hexadecimal using formatters as well as other bases (arbitrary from 2 to 36) using a dedicated method
-//! - Borrowed Linked Bytes (required for EHB) — a Linked Bytes number which doesn't own its contents and is a slice into an EHB number (still a stub)
+//! - Borrowed Linked Bytes (required for EHB) — a Linked Bytes number which doesn't own its contents and is a slice into an EHB number or any other byte buffer, decoded and compared without copying (still a stub otherwise)
 //!
 //! And here's a list of what's not finished just yet:
-//! - Creating \[E\]HB numbers from primitive integers and `f32`/`f64` (most likely will be added in 0.1.0)
-//! - The Extended Header Byte format (will be added in 0.0.x)
-//! - Arithmetic operations (addition, subtraction, multiplication and division are all defined by the BigBit standard) for \[E\]HB; the main issue is dealing with the exponents (will mark the 1.0.0 release, might be partially added over the course of 0.x.x releases)
-//! - Strings encoded using Linked Bytes (will be added in 0.0.x)
-//! - `Debug` and `Display` formatting for \[E\]HB (i.e. converting the numbers either into a debugging-friendly representation as an array of bytes or a string representing the number in decimal scientific notation or full notation, as well as other numeric notations; simple `Debug` and `Display` decimal formatting will be added in 0.1.0 while the rest is planned for 1.0.0)
+//! - Creating EHB numbers from primitive integers and `f32`/`f64` (most likely will be added in 0.1.0)
+//! - Arithmetic operations (addition, subtraction, multiplication and division are all defined by the BigBit standard) for EHB; the main issue is dealing with the exponents (will mark the 1.0.0 release, might be partially added over the course of 0.x.x releases)
+//! - `Display` formatting for EHB (i.e. converting the numbers into a string representing the number in decimal scientific notation or full notation, as well as other numeric notations; simple decimal formatting will be added in 0.1.0 while the rest is planned for 1.0.0)
 //! - **Tests** (planned for 0.1.0 but might be partially added earlier)
 //!
 //! # Feature flags
 //! Several [Cargo feature flags][CargoFeatures] are available:
 //! - **`std`** *(enabled by default)* — disables `no_std`, allowing for `std`-dependent trait implementations. **Disable this feature if using `no_std`.**
 //! - **`num_traits`** *(enabled by default)* — enables trait implementations for traits from [`num-traits`], disable to insignificantly decrease compile time and code size. **The current version of `num-traits` is `0.2.x` — please open an issue if a new one comes out.**
+//! - **`serde`** — enables implementations of `Serialize` and `Deserialize` from [`serde`] for `LBNum`, `LBString`, `HBNum`, `HeadByte`, `Exponent` and `Sign`. Human-readable formats get the numbers as decimal strings, while binary formats get the compact BigBit byte encoding. Works without `std`.
+//! - **`rand`** — enables generating random numbers using [`rand`]: distributions and helper methods for Linked Bytes numbers with a specific bit length, encoded length or below a bound in the [`random`][lbrandom] module, the `Standard` distribution for `HBNum`, `HeadByte`, `Exponent` and `Sign`, covering every valid value, as well as using any random number generator from `rand` for [primality testing][primality]. Works without `std`.
 //! - **`clippy`** *(enabled by default)* — disable to remove all mentions of Clippy lints to avoid unknown lint errors if working on this crate without Clippy installed.
 //!
 //! # Changelog
@@ -36,6 +44,10 @@
 //! [changelog]: https://github.com/kotauskas/bigbit.rs/releases " "
 //! [CargoFeatures]: https://doc.rust-lang.org/cargo/reference/features.html "Documentation for crate features on the Cargo Reference"
 //! [`num-traits`]: https://crates.io/crates/num-traits "num-traits on Crates.io"
+//! [`serde`]: https://crates.io/crates/serde "serde on Crates.io"
+//! [`rand`]: https://crates.io/crates/rand "rand on Crates.io"
+//! [lbrandom]: linkedbytes/random/index.html "bigbit::linkedbytes::random — generating random Linked Bytes numbers"
+//! [primality]: primality/index.html "bigbit::primality — primality testing and prime generation for Linked Bytes numbers"
 
 #![cfg_attr(feature = "clippy", warn(clippy::pedantic, clippy::nursery))]
 #![cfg_attr(feature = "clippy", allow( // All of these lints are generally bullshit and should not be a thing or require serious improvement.
@@ -50,6 +62,12 @@
     clippy::redundant_pub_crate,
     clippy::if_not_else,
     clippy::cast_lossless, // What does this even mean?
+    clippy::cast_precision_loss, // Conversions to floats are lossy by definition.
+    clippy::elidable_lifetime_names, // Operator impls name their lifetimes to make the lhs/rhs distinction obvious.
+    clippy::semicolon_if_nothing_returned,
+    clippy::option_if_let_else,
+    clippy::manual_assert,
+    clippy::too_long_first_doc_paragraph, // The first paragraph is the summary, and it can be as long as it needs to be.
 ))]
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -57,11 +75,11 @@ extern crate alloc;
 
 pub mod headbyte;
 pub use crate::headbyte::{HBNum, HeadByte};
-// Uncomment these after adding EHB:
-//pub mod extheadbyte;
-//pub use extheadbyte::{EHBNum, ExtHeadByte};
+pub mod extheadbyte;
+pub use extheadbyte::{EHBNum, ExtHeadByte};
 pub mod linkedbytes;
-pub use linkedbytes::{LBNum, LBString, LinkedByte};
+pub use linkedbytes::{LBNum, LBInt, LBString, LinkedByte};
+pub mod primality;
 
 mod traits;
 pub use traits::*;
@@ -70,11 +88,15 @@ pub use traits::*;
 pub extern crate num_traits;
 
 pub(crate) mod tables; pub(crate) use tables::*;
+pub(crate) mod float;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_bytes;
 
 /// Basic types which can and should be in scope when using BigBit.
 pub mod prelude {
-    pub use crate::linkedbytes::{LBNum, LBString, LinkedByte};
+    pub use crate::linkedbytes::{LBNum, LBInt, LBString, LinkedByte};
     pub use crate::headbyte::{HBNum, HeadByte};
+    pub use crate::extheadbyte::{EHBNum, ExtHeadByte};
 }
 
 /// Calculates the greatest common divisor of two numbers.
@@ -85,6 +107,28 @@ pub fn gcd<A, B>(lhs: A, rhs: B) -> A
 where A: Gcd<B> {
     A::gcd(lhs, rhs)
 }
+/// Calculates the least common multiple of two numbers.
+///
+/// This is an alias for using the [`Gcd`] trait directly, see [`gcd`] for the reasoning.
+///
+/// [`Gcd`]: trait.Gcd.html "Gcd — calculating the greatest common divisor"
+/// [`gcd`]: fn.gcd.html "gcd — calculates the greatest common divisor of two numbers"
+#[inline(always)]
+pub fn lcm<A, B>(lhs: A, rhs: B) -> A
+where A: Gcd<B> {
+    A::lcm(lhs, rhs)
+}
+/// Calculates the greatest common divisor of two numbers along with the Bézout coefficients.
+///
+/// This is an alias for using the [`ExtendedGcd`] trait directly, see [`gcd`] for the reasoning.
+///
+/// [`ExtendedGcd`]: trait.ExtendedGcd.html "ExtendedGcd — calculating the greatest common divisor along with the Bézout coefficients"
+/// [`gcd`]: fn.gcd.html "gcd — calculates the greatest common divisor of two numbers"
+#[inline(always)]
+pub fn extended_gcd<A, B>(lhs: A, rhs: B) -> (A, A::Coefficient, A::Coefficient)
+where A: ExtendedGcd<B> {
+    A::extended_gcd(lhs, rhs)
+}
 
 /// The sign of a number.
 ///
@@ -121,6 +165,66 @@ impl core::fmt::Display for Sign {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Sign {
+    /// Serializes the sign as a unit variant of an enum, which is exactly what `#[derive(Serialize)]` would do.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Positive => serializer.serialize_unit_variant("Sign", 0, "Positive"),
+            Self::Negative => serializer.serialize_unit_variant("Sign", 1, "Negative"),
+        }
+    }
+}
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Sign {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{self, Visitor, EnumAccess, VariantAccess, Unexpected};
+        const VARIANTS: &[&str] = &["Positive", "Negative"];
+        struct SignVisitor;
+        impl<'de> Visitor<'de> for SignVisitor {
+            type Value = Sign;
+            fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                f.write_str("a sign, either Positive or Negative")
+            }
+            // The variant identifier comes in here, and so does the entire sign in formats which serialize unit variants as strings or integers.
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Sign, E> {
+                match v {
+                    "Positive" => Ok(Sign::Positive),
+                    "Negative" => Ok(Sign::Negative),
+                    _ => Err(E::unknown_variant(v, VARIANTS)),
+                }
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Sign, E> {
+                match v {
+                    0 => Ok(Sign::Positive),
+                    1 => Ok(Sign::Negative),
+                    _ => Err(E::invalid_value(Unexpected::Unsigned(v), &self)),
+                }
+            }
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Sign, A::Error> {
+                let (sign, variant) = data.variant_seed(self)?;
+                variant.unit_variant()?;
+                Ok(sign)
+            }
+        }
+        impl<'de> de::DeserializeSeed<'de> for SignVisitor {
+            type Value = Sign;
+            fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Sign, D::Error> {
+                deserializer.deserialize_identifier(self)
+            }
+        }
+        deserializer.deserialize_enum("Sign", VARIANTS, SignVisitor)
+    }
+}
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Sign> for rand::distributions::Standard {
+    /// Samples either sign with equal probability.
+    #[inline]
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Sign {
+        Sign::from(rng.gen::<bool>())
+    }
+}
+
 /// This is a hack to get around the fact that `debug_struct` only accepts `Debug` formatting rather than `Display`, which becomes a verbosity issue if the values of an enum are known from the name of the field.
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub(crate) struct SignDisplayAsDebug(pub(crate) Sign);
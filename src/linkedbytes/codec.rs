@@ -0,0 +1,263 @@
+use super::{LBNum, LBNumRef, LinkedByte, InvalidLBSequence, DecodeResult};
+use core::iter::FusedIterator;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
+
+impl LBNum {
+    /// Returns the number of bytes the number occupies when encoded. This doesn't allocate memory, so it can be used to pre-size buffers.
+    ///
+    /// This is the same as [`num_bytes`][0], except for zero, which is encoded as a single endpoint byte rather than as nothing.
+    ///
+    /// [0]: #method.num_bytes "num_bytes — the amount of bytes used in the number"
+    #[inline(always)]
+    pub fn encoded_len(&self) -> usize {
+        self.num_bytes().max(1)
+    }
+    /// Encodes the number into its canonical byte representation, in **little**-endian byte order.
+    ///
+    /// Zero is encoded as a single endpoint byte, so that the result can always be read back using [`decode_prefix`][0].
+    ///
+    /// [0]: #method.decode_prefix "decode_prefix — decodes a Linked Bytes number from the beginning of a byte slice"
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.encoded_len());
+        self.encode_into(&mut result);
+        result
+    }
+    /// Appends the canonical byte representation of the number to the specified buffer.
+    ///
+    /// See [`to_bytes`][0] for the details.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into its canonical byte representation"
+    pub fn encode_into(&self, buffer: &mut Vec<u8>) {
+        if self.num_bytes() == 0 {
+            buffer.push(LinkedByte::ZERO_END.into_inner());
+        } else {
+            buffer.extend_from_slice(raw_bytes(self.0.inner()));
+        }
+    }
+    /// Writes the canonical byte representation of the number into the specified writer.
+    ///
+    /// See [`to_bytes`][0] for the details.
+    ///
+    /// # Errors
+    /// Any I/O errors returned by the writer are passed through.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into its canonical byte representation"
+    #[cfg(feature = "std")]
+    pub fn write_to<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        if self.num_bytes() == 0 {
+            writer.write_all(&[LinkedByte::ZERO_END.into_inner()])
+        } else {
+            writer.write_all(raw_bytes(self.0.inner()))
+        }
+    }
+
+    /// Decodes a Linked Bytes number from the beginning of a byte slice, returning it along with the number of bytes it occupied. **Little-endian byte order is assumed, regardless of platform.**
+    ///
+    /// The number ends at the first endpoint byte (most significant bit cleared). Any bytes after it are left alone, which allows parsing several numbers stored back-to-back — see [`decode_iter`][0] for a convenient way to do that. Redundant zero bytes at the most significant end are dropped from the result, but are still counted as occupied.
+    ///
+    /// # Errors
+    /// If the slice ends before an endpoint byte is encountered, [`InvalidLBSequence`][1] is returned.
+    ///
+    /// [0]: #method.decode_iter "decode_iter — returns an iterator which decodes back-to-back Linked Bytes numbers from a byte slice"
+    /// [1]: struct.InvalidLBSequence.html "InvalidLBSequence — marker error type representing that the decoder has encountered an invalid Linked Bytes sequence"
+    pub fn decode_prefix(op: &[u8]) -> Result<(Self, usize), InvalidLBSequence> {
+        let len = op.iter()
+            .position(|&byte| (byte & LinkedByte::LINK_MASK) == 0)
+            .ok_or(InvalidLBSequence)?
+            + 1;
+        let mut result = op[..len].iter()
+            .map(|&byte| LinkedByte::from(byte))
+            .collect::<Self>();
+        result.zero_fold();
+        Ok((result, len))
+    }
+    /// Decodes a Linked Bytes number from the beginning of a byte slice, rejecting it if it's not in the canonical form. This guarantees that every value can only be decoded from one byte sequence, which is important when the encoded numbers are hashed or signed.
+    ///
+    /// The canonical form is the one produced by [`to_bytes`][0]: there are no redundant zero bytes at the most significant end, and zero is encoded as a single endpoint byte. Otherwise, this works just like [`decode_prefix`][1].
+    ///
+    /// # Errors
+    /// If the slice ends before an endpoint byte is encountered or the number is not in the canonical form, [`InvalidLBSequence`][2] is returned.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into its canonical byte representation"
+    /// [1]: #method.decode_prefix "decode_prefix — decodes a Linked Bytes number from the beginning of a byte slice"
+    /// [2]: struct.InvalidLBSequence.html "InvalidLBSequence — marker error type representing that the decoder has encountered an invalid Linked Bytes sequence"
+    pub fn decode_prefix_strict(op: &[u8]) -> Result<(Self, usize), InvalidLBSequence> {
+        let (result, len) = Self::decode_prefix(op)?;
+        if len != result.encoded_len() {
+            // Only redundant zero bytes can make the encoding longer than the canonical one.
+            return Err(InvalidLBSequence);
+        }
+        Ok((result, len))
+    }
+    /// Returns an iterator which decodes back-to-back Linked Bytes numbers from a byte slice, until the slice is exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::LBNum;
+    /// let buffer = [0b1_0000001, 0b0_0000001, 0b0_0000101];
+    /// let numbers = LBNum::decode_iter(&buffer).collect::<Result<Vec<_>, _>>().unwrap();
+    /// assert_eq!(numbers, [129_u8, 5_u8]);
+    /// ```
+    #[inline(always)]
+    pub const fn decode_iter(op: &[u8]) -> LBDecodeIter<'_> {
+        LBDecodeIter::new(op)
+    }
+    /// Reads a single Linked Bytes number from the specified reader, stopping right after its endpoint byte.
+    ///
+    /// The bytes are read one by one, so wrapping unbuffered readers into a [`BufReader`][0] is highly recommended.
+    ///
+    /// # Errors
+    /// Any I/O errors returned by the reader are passed through. If the reader reaches end-of-file before an endpoint byte is encountered, an error of kind `UnexpectedEof` is returned.
+    ///
+    /// [0]: https://doc.rust-lang.org/std/io/struct.BufReader.html "std::io::BufReader — adds buffering to any reader"
+    #[cfg(feature = "std")]
+    pub fn read_from<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut result = Self::ZERO;
+        let mut byte = [0_u8];
+        loop {
+            reader.read_exact(&mut byte)?;
+            let byte = LinkedByte::from((byte[0], (byte[0] & LinkedByte::LINK_MASK) != 0));
+            result.0.inner_mut().push(byte);
+            if byte.is_end() {break;}
+        }
+        result.zero_fold();
+        Ok(result)
+    }
+}
+
+/// An iterator which decodes back-to-back Linked Bytes numbers from a byte slice.
+///
+/// Created by [`LBNum::decode_iter`][0]. If the slice ends in the middle of a number, an error is produced, after which the iterator is finished.
+///
+/// [0]: struct.LBNum.html#method.decode_iter "LBNum::decode_iter — returns an iterator which decodes back-to-back Linked Bytes numbers from a byte slice"
+#[derive(Clone, Debug)]
+pub struct LBDecodeIter<'a> {
+    remainder: &'a [u8],
+}
+impl<'a> LBDecodeIter<'a> {
+    /// Creates an iterator over the numbers in the specified byte slice.
+    #[inline(always)]
+    pub const fn new(op: &'a [u8]) -> Self {
+        Self {remainder: op}
+    }
+    /// Returns the part of the slice which hasn't been decoded yet.
+    #[inline(always)]
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+impl<'a> Iterator for LBDecodeIter<'a> {
+    type Item = DecodeResult;
+    fn next(&mut self) -> Option<DecodeResult> {
+        if self.remainder.is_empty() {return None;}
+        match LBNum::decode_prefix(self.remainder) {
+            Ok((result, len)) => {
+                self.remainder = &self.remainder[len..];
+                Some(Ok(result))
+            },
+            Err(e) => {
+                self.remainder = &[];
+                Some(Err(e))
+            },
+        }
+    }
+}
+impl FusedIterator for LBDecodeIter<'_> {}
+
+impl<'a> LBNumRef<'a> {
+    /// Borrows a Linked Bytes number from the beginning of a byte slice without copying it, returning it along with the number of bytes it occupied. **Little-endian byte order is assumed, regardless of platform.**
+    ///
+    /// The bytes are validated in place, which makes this suitable for memory-mapped files and other buffers which shouldn't be copied. Since a borrowed number cannot drop anything, the number has to be in the canonical form, as produced by [`LBNum::to_bytes`][0]; zero, which is encoded as a single endpoint byte, becomes an empty slice.
+    ///
+    /// # Errors
+    /// If the slice ends before an endpoint byte is encountered or the number has redundant zero bytes at the most significant end, [`InvalidLBSequence`][1] is returned.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::linkedbytes::LBNumRef;
+    /// # use core::convert::TryFrom;
+    /// let buffer = [0b1_0101100, 0b0_0000010, 0xFF];
+    /// let (number, len) = LBNumRef::from_bytes(&buffer).unwrap();
+    /// assert_eq!(len, 2);
+    /// assert_eq!(u64::try_from(number), Ok(300));
+    /// ```
+    ///
+    /// [0]: ../struct.LBNum.html#method.to_bytes "LBNum::to_bytes — encodes the number into its canonical byte representation"
+    /// [1]: struct.InvalidLBSequence.html "InvalidLBSequence — marker error type representing that the decoder has encountered an invalid Linked Bytes sequence"
+    pub fn from_bytes(op: &'a [u8]) -> Result<(Self, usize), InvalidLBSequence> {
+        let len = op.iter()
+            .position(|&byte| (byte & LinkedByte::LINK_MASK) == 0)
+            .ok_or(InvalidLBSequence)?
+            + 1;
+        let digits = match op[len - 1] {
+            0 if len == 1 => &[][..],
+            0 => return Err(InvalidLBSequence),
+            _ => linked_bytes(&op[..len]),
+        };
+        // All the bytes before the endpoint are linked, since it's the first one which isn't.
+        Ok((unsafe {Self::new_unchecked(digits)}, len))
+    }
+    /// Returns an iterator which borrows back-to-back Linked Bytes numbers from a byte slice, until the slice is exhausted.
+    ///
+    /// See [`from_bytes`][0] for the requirements on the numbers.
+    ///
+    /// [0]: #method.from_bytes "from_bytes — borrows a Linked Bytes number from the beginning of a byte slice without copying it"
+    #[inline(always)]
+    pub const fn decode_iter(op: &'a [u8]) -> LBRefDecodeIter<'a> {
+        LBRefDecodeIter::new(op)
+    }
+}
+/// Reinterprets bytes as Linked Bytes without copying them.
+#[inline(always)]
+const fn linked_bytes(op: &[u8]) -> &[LinkedByte] {
+    // LinkedByte is a transparent wrapper around u8, so the layouts are identical, and every u8 is a valid LinkedByte.
+    unsafe {&*(op as *const [u8] as *const [LinkedByte])}
+}
+/// Reinterprets Linked Bytes as plain bytes without copying them, the inverse of `linked_bytes`.
+#[inline(always)]
+const fn raw_bytes(op: &[LinkedByte]) -> &[u8] {
+    // Same as above, and every LinkedByte holds a plain u8.
+    unsafe {&*(op as *const [LinkedByte] as *const [u8])}
+}
+
+/// An iterator which borrows back-to-back Linked Bytes numbers from a byte slice without copying them.
+///
+/// Created by [`LBNumRef::decode_iter`][0]. If the slice ends in the middle of a number or a number is not in the canonical form, an error is produced, after which the iterator is finished.
+///
+/// [0]: struct.LBNumRef.html#method.decode_iter "LBNumRef::decode_iter — returns an iterator which borrows back-to-back Linked Bytes numbers from a byte slice"
+#[derive(Clone, Debug)]
+pub struct LBRefDecodeIter<'a> {
+    remainder: &'a [u8],
+}
+impl<'a> LBRefDecodeIter<'a> {
+    /// Creates an iterator over the numbers in the specified byte slice.
+    #[inline(always)]
+    pub const fn new(op: &'a [u8]) -> Self {
+        Self {remainder: op}
+    }
+    /// Returns the part of the slice which hasn't been decoded yet.
+    #[inline(always)]
+    pub const fn remainder(&self) -> &'a [u8] {
+        self.remainder
+    }
+}
+impl<'a> Iterator for LBRefDecodeIter<'a> {
+    type Item = Result<LBNumRef<'a>, InvalidLBSequence>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remainder.is_empty() {return None;}
+        match LBNumRef::from_bytes(self.remainder) {
+            Ok((result, len)) => {
+                self.remainder = &self.remainder[len..];
+                Some(Ok(result))
+            },
+            Err(e) => {
+                self.remainder = &[];
+                Some(Err(e))
+            },
+        }
+    }
+}
+impl FusedIterator for LBRefDecodeIter<'_> {}
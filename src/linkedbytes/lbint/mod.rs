@@ -0,0 +1,157 @@
+//! Signed integers implemented using Linked Bytes.
+//!
+//! This module is the home for [`LBInt`][lbi], which stores the sign separately from the absolute value, which is a normal [`LBNum`][lbn]. When encoded into bytes, the number is converted into an `LBNum` using the [zigzag encoding][zz] first, so that small negative numbers stay as compact as small positive ones.
+//!
+//! [lbi]: struct.LBInt.html "LBInt — a signed integer stored using the Linked Bytes format"
+//! [lbn]: ../struct.LBNum.html "LBNum — a number in the Linked Bytes format, capable of storing arbitrarily large non-negative integers"
+//! [zz]: https://developers.google.com/protocol-buffers/docs/encoding#signed-integers "Signed integers in the Protocol Buffers encoding"
+
+mod ops;
+
+use super::{LBNum, InvalidLBSequence};
+use crate::{Sign, DivRem};
+use alloc::vec::Vec;
+
+/// A signed integer stored using the Linked Bytes format.
+///
+/// The sign and the absolute value (magnitude) are stored separately. Zero is **always positive**, which is enforced by all the methods which create `LBInt`s.
+///
+/// # Usage
+/// ```
+/// # use bigbit::{LBInt, Sign};
+/// let a = LBInt::from(-300_i32);
+/// let b = LBInt::from(200_u8);
+/// assert_eq!(&a + &b, -100_i32);
+/// assert_eq!(a.sign(), Sign::Negative);
+/// assert_eq!(-a, 300_u16);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LBInt {
+    sign: Sign,
+    magnitude: LBNum,
+}
+impl LBInt {
+    /// The zero value.
+    ///
+    /// This does not allocate memory.
+    pub const ZERO: Self = Self {sign: Sign::Positive, magnitude: LBNum::ZERO};
+
+    /// Creates a number from its sign and absolute value. Negative zero is turned into positive zero.
+    #[inline]
+    pub fn new(sign: Sign, magnitude: LBNum) -> Self {
+        let sign = if magnitude.num_bytes() == 0 {Sign::Positive} else {sign};
+        Self {sign, magnitude}
+    }
+    /// Returns the sign of the number.
+    #[inline(always)]
+    pub const fn sign(&self) -> Sign {
+        self.sign
+    }
+    /// Returns the absolute value of the number, as an `LBNum` reference.
+    #[inline(always)]
+    pub const fn magnitude(&self) -> &LBNum {
+        &self.magnitude
+    }
+    /// Consumes the number and returns its sign and absolute value.
+    #[inline(always)]
+    #[cfg_attr(feature = "clippy", allow(clippy::missing_const_for_fn))] // Destructors cannot be invoked at compile time just yet
+    pub fn into_parts(self) -> (Sign, LBNum) {
+        (self.sign, self.magnitude)
+    }
+    /// Consumes the number and returns its absolute value.
+    #[inline(always)]
+    #[cfg_attr(feature = "clippy", allow(clippy::missing_const_for_fn))] // Destructors cannot be invoked at compile time just yet
+    pub fn unsigned_abs(self) -> LBNum {
+        self.magnitude
+    }
+    /// Returns `true` if the number is negative, `false` if it's zero or positive.
+    #[inline(always)]
+    pub fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative
+    }
+    /// Returns `true` if the number is positive, `false` if it's zero or negative.
+    #[inline(always)]
+    pub fn is_positive(&self) -> bool {
+        self.sign == Sign::Positive && self.magnitude.num_bytes() != 0
+    }
+    /// Returns `true` if the number is zero, `false` otherwise.
+    #[inline(always)]
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.num_bytes() == 0
+    }
+
+    /// Converts the number into an `LBNum` using the zigzag encoding: non-negative numbers are doubled, while negative numbers are mapped to odd numbers, so that 0, -1, 1, -2, 2… become 0, 1, 2, 3, 4…
+    pub fn to_zigzag(&self) -> LBNum {
+        let doubled = &self.magnitude + &self.magnitude;
+        match self.sign {
+            Sign::Positive => doubled,
+            Sign::Negative => doubled - 1_u8,
+        }
+    }
+    /// Converts an `LBNum` produced by [`to_zigzag`][0] back into a signed number.
+    ///
+    /// [0]: #method.to_zigzag "to_zigzag — converts the number into an LBNum using the zigzag encoding"
+    pub fn from_zigzag(op: LBNum) -> Self {
+        let (half, remainder) = op.div_rem(2_u8);
+        if remainder == 0_u8 {
+            Self::new(Sign::Positive, half)
+        } else {
+            Self::new(Sign::Negative, half + 1_u8)
+        }
+    }
+    /// Encodes the number into bytes by converting it using the [zigzag encoding][0] and encoding the result as an `LBNum`.
+    ///
+    /// [0]: #method.to_zigzag "to_zigzag — converts the number into an LBNum using the zigzag encoding"
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_zigzag().to_bytes()
+    }
+    /// Appends the byte representation of the number to the specified buffer.
+    ///
+    /// See [`to_bytes`][0] for the details.
+    ///
+    /// [0]: #method.to_bytes "to_bytes — encodes the number into bytes"
+    #[inline]
+    pub fn encode_into(&self, buffer: &mut Vec<u8>) {
+        self.to_zigzag().encode_into(buffer);
+    }
+    /// Decodes a number from the beginning of a byte slice, returning it along with the number of bytes it occupied.
+    ///
+    /// See [`LBNum::decode_prefix`][0] for the details.
+    ///
+    /// # Errors
+    /// If the slice ends before an endpoint byte is encountered, [`InvalidLBSequence`][1] is returned.
+    ///
+    /// [0]: ../struct.LBNum.html#method.decode_prefix "LBNum::decode_prefix — decodes a Linked Bytes number from the beginning of a byte slice"
+    /// [1]: ../struct.InvalidLBSequence.html "InvalidLBSequence — marker error type representing that the decoder has encountered an invalid Linked Bytes sequence"
+    #[inline]
+    pub fn decode_prefix(op: &[u8]) -> Result<(Self, usize), InvalidLBSequence> {
+        LBNum::decode_prefix(op).map(|(zigzag, len)| (Self::from_zigzag(zigzag), len))
+    }
+    /// Decodes a number from the beginning of a byte slice, rejecting it if it's not in the canonical form.
+    ///
+    /// See [`LBNum::decode_prefix_strict`][0] for the details.
+    ///
+    /// # Errors
+    /// If the slice ends before an endpoint byte is encountered or the number is not in the canonical form, [`InvalidLBSequence`][1] is returned.
+    ///
+    /// [0]: ../struct.LBNum.html#method.decode_prefix_strict "LBNum::decode_prefix_strict — decodes a Linked Bytes number in the canonical form from the beginning of a byte slice"
+    /// [1]: ../struct.InvalidLBSequence.html "InvalidLBSequence — marker error type representing that the decoder has encountered an invalid Linked Bytes sequence"
+    #[inline]
+    pub fn decode_prefix_strict(op: &[u8]) -> Result<(Self, usize), InvalidLBSequence> {
+        LBNum::decode_prefix_strict(op).map(|(zigzag, len)| (Self::from_zigzag(zigzag), len))
+    }
+}
+impl From<LBNum> for LBInt {
+    /// Creates a positive number with the specified absolute value.
+    #[inline(always)]
+    fn from(op: LBNum) -> Self {
+        Self::new(Sign::Positive, op)
+    }
+}
+impl Default for LBInt {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
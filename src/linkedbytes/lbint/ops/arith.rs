@@ -0,0 +1,188 @@
+use super::super::LBInt;
+use crate::{
+    linkedbytes::LBNum,
+    Sign, DivRem, DivRemAssign,
+};
+use core::ops::{
+    Add, Sub, Mul, Div, Rem,
+    AddAssign, SubAssign, MulAssign, DivAssign, RemAssign,
+};
+
+impl LBInt {
+    /// Adds a number with the specified sign and absolute value.
+    fn add_signed(&self, rhs_sign: Sign, rhs: &LBNum) -> Self {
+        if self.sign == rhs_sign {
+            Self::new(self.sign, &self.magnitude + rhs)
+        } else if self.magnitude >= *rhs {
+            Self::new(self.sign, self.magnitude.clone() - rhs)
+        } else {
+            Self::new(rhs_sign, rhs.clone() - &self.magnitude)
+        }
+    }
+    fn add_ref(&self, rhs: &Self) -> Self {
+        self.add_signed(rhs.sign, &rhs.magnitude)
+    }
+    fn sub_ref(&self, rhs: &Self) -> Self {
+        let rhs_sign = match rhs.sign {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        };
+        self.add_signed(rhs_sign, &rhs.magnitude)
+    }
+    fn mul_ref(&self, rhs: &Self) -> Self {
+        Self::new(Sign::from(self.sign != rhs.sign), &self.magnitude * &rhs.magnitude)
+    }
+    /// Performs truncating division, i.e. the quotient is rounded towards zero and the remainder has the same sign as the dividend, just like with primitive integers.
+    fn div_rem_ref(&self, rhs: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.clone().div_rem(&rhs.magnitude);
+        (
+            Self::new(Sign::from(self.sign != rhs.sign), quotient),
+            Self::new(self.sign, remainder),
+        )
+    }
+    fn div_ref(&self, rhs: &Self) -> Self {
+        self.div_rem_ref(rhs).0
+    }
+    fn rem_ref(&self, rhs: &Self) -> Self {
+        self.div_rem_ref(rhs).1
+    }
+}
+
+macro_rules! impl_binop {
+    ($($tr:ident $method:ident $assign_tr:ident $assign_method:ident $core:ident;)+) => ($(
+        impl<'l, 'r> $tr<&'r LBInt> for &'l LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: &'r LBInt) -> LBInt {
+                self.$core(rhs)
+            }
+        }
+        impl<'l> $tr<LBInt> for &'l LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: LBInt) -> LBInt {
+                self.$core(&rhs)
+            }
+        }
+        impl<'r> $tr<&'r LBInt> for LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: &'r LBInt) -> LBInt {
+                self.$core(rhs)
+            }
+        }
+        impl $tr<LBInt> for LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: LBInt) -> LBInt {
+                self.$core(&rhs)
+            }
+        }
+        impl $assign_tr<&LBInt> for LBInt {
+            #[inline(always)]
+            fn $assign_method(&mut self, rhs: &LBInt) {
+                *self = self.$core(rhs);
+            }
+        }
+        impl $assign_tr<LBInt> for LBInt {
+            #[inline(always)]
+            fn $assign_method(&mut self, rhs: LBInt) {
+                *self = self.$core(&rhs);
+            }
+        }
+    )+)
+}
+impl_binop! {
+    Add add AddAssign add_assign add_ref;
+    Sub sub SubAssign sub_assign sub_ref;
+    Mul mul MulAssign mul_assign mul_ref;
+    Div div DivAssign div_assign div_ref;
+    Rem rem RemAssign rem_assign rem_ref;
+}
+
+impl DivRem<&Self> for LBInt {
+    type Quotient = Self;
+    type Remainder = Self;
+    /// Performs truncating division, i.e. the quotient is rounded towards zero and the remainder has the same sign as the dividend, just like with primitive integers.
+    #[inline(always)]
+    fn div_rem(self, rhs: &Self) -> (Self, Self) {
+        self.div_rem_ref(rhs)
+    }
+}
+impl DivRem<Self> for LBInt {
+    type Quotient = Self;
+    type Remainder = Self;
+    /// Performs truncating division, i.e. the quotient is rounded towards zero and the remainder has the same sign as the dividend, just like with primitive integers.
+    #[inline(always)]
+    fn div_rem(self, rhs: Self) -> (Self, Self) {
+        self.div_rem_ref(&rhs)
+    }
+}
+impl DivRemAssign<&Self> for LBInt {
+    type Remainder = Self;
+    #[inline(always)]
+    fn div_rem_assign(&mut self, rhs: &Self) -> Self {
+        let (quotient, remainder) = self.div_rem_ref(rhs);
+        *self = quotient;
+        remainder
+    }
+}
+impl DivRemAssign<Self> for LBInt {
+    type Remainder = Self;
+    #[inline(always)]
+    fn div_rem_assign(&mut self, rhs: Self) -> Self {
+        self.div_rem_assign(&rhs)
+    }
+}
+
+macro_rules! impl_ops_with_primitive {
+    ($($ty:ident)+) => ($(
+        impl_ops_with_primitive! {@op $ty;
+            Add add AddAssign add_assign add_ref;
+            Sub sub SubAssign sub_assign sub_ref;
+            Mul mul MulAssign mul_assign mul_ref;
+            Div div DivAssign div_assign div_ref;
+            Rem rem RemAssign rem_assign rem_ref;
+        }
+    )+);
+    (@op $ty:ident; $($tr:ident $method:ident $assign_tr:ident $assign_method:ident $core:ident;)+) => ($(
+        impl $tr<$ty> for LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: $ty) -> LBInt {
+                self.$core(&LBInt::from(rhs))
+            }
+        }
+        impl<'l> $tr<$ty> for &'l LBInt {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: $ty) -> LBInt {
+                self.$core(&LBInt::from(rhs))
+            }
+        }
+        impl $tr<LBInt> for $ty {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: LBInt) -> LBInt {
+                LBInt::from(self).$core(&rhs)
+            }
+        }
+        impl<'r> $tr<&'r LBInt> for $ty {
+            type Output = LBInt;
+            #[inline(always)]
+            fn $method(self, rhs: &'r LBInt) -> LBInt {
+                LBInt::from(self).$core(rhs)
+            }
+        }
+        impl $assign_tr<$ty> for LBInt {
+            #[inline(always)]
+            fn $assign_method(&mut self, rhs: $ty) {
+                *self = self.$core(&LBInt::from(rhs));
+            }
+        }
+    )+);
+}
+impl_ops_with_primitive! {
+    u8 u16 u32 u64 u128 usize
+    i8 i16 i32 i64 i128 isize
+}
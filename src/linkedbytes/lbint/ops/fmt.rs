@@ -0,0 +1,94 @@
+use super::super::LBInt;
+use crate::{
+    linkedbytes::{LBNum, ParseLBNumError, ParseLBNumErrorKind},
+    Sign,
+};
+use core::{
+    fmt::{self, Formatter},
+    str::FromStr,
+};
+
+impl LBInt {
+    /// Converts the number into a string with an arbitrary radix (base), from 2 to 36 inclusively. Negative numbers are prefixed with `-`.
+    ///
+    /// See [`LBNum::into_string_with_radix`][0] for the details.
+    ///
+    /// # Panics
+    /// Passing a radix less than 2 or greater than 36 results an immediate panic, even if the value is 0.
+    ///
+    /// [0]: ../struct.LBNum.html#method.into_string_with_radix "LBNum::into_string_with_radix — converts a Linked Bytes number into a string with an arbitrary radix (base)"
+    pub fn into_string_with_radix(self, radix: u8) -> alloc::string::String {
+        let mut result = self.magnitude.into_string_with_radix(radix);
+        if self.sign == Sign::Negative {
+            result.insert(0, '-');
+        }
+        result
+    }
+    /// Parses a signed Linked Bytes number from a string with an arbitrary radix (base), from 2 to 36 inclusively.
+    ///
+    /// The syntax is the same as for [`LBNum::from_str_radix`][0], except that a leading `-` is allowed as well.
+    ///
+    /// # Errors
+    /// See [`LBNum::from_str_radix`][0].
+    ///
+    /// # Panics
+    /// Passing a radix less than 2 or greater than 36 results an immediate panic, even if the string is empty.
+    ///
+    /// [0]: ../struct.LBNum.html#method.from_str_radix "LBNum::from_str_radix — parses a Linked Bytes number from a string with an arbitrary radix (base)"
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseLBNumError> {
+        Self::parse_signed(src, |magnitude| LBNum::from_str_radix(magnitude, radix))
+    }
+    /// Strips the `-` sign and parses the rest using the specified function, adjusting the error positions.
+    fn parse_signed(
+        src: &str,
+        parse_magnitude: impl FnOnce(&str) -> Result<LBNum, ParseLBNumError>,
+    ) -> Result<Self, ParseLBNumError> {
+        let (sign, magnitude) = match src.strip_prefix('-') {
+            Some(magnitude) => (Sign::Negative, magnitude),
+            None => (Sign::Positive, src),
+        };
+        let offset = src.len() - magnitude.len();
+        if offset != 0 && magnitude.starts_with('+') {
+            return Err(ParseLBNumError {kind: ParseLBNumErrorKind::InvalidDigit('+'), position: offset});
+        }
+        parse_magnitude(magnitude)
+            .map(|magnitude| Self::new(sign, magnitude))
+            .map_err(|e| ParseLBNumError {position: e.position + offset, ..e})
+    }
+}
+impl FromStr for LBInt {
+    type Err = ParseLBNumError;
+    /// Parses a signed Linked Bytes number from a string. The syntax is the same as for [`LBNum`'s `FromStr` implementation][0], except that a leading `-` is allowed as well.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::LBInt;
+    /// let number: LBInt = "-0x7F".parse().unwrap();
+    /// assert_eq!(number, -127_i8);
+    /// ```
+    ///
+    /// [0]: ../struct.LBNum.html#impl-FromStr "FromStr for LBNum"
+    #[inline(always)]
+    fn from_str(src: &str) -> Result<Self, ParseLBNumError> {
+        Self::parse_signed(src, LBNum::from_str)
+    }
+}
+
+macro_rules! impl_fmt {
+    ($($tr:ident $radix:literal $prefix:literal $lowercase:literal)+) => ($(
+        impl fmt::$tr for LBInt {
+            fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+                let mut digits = self.magnitude.clone().into_string_with_radix($radix);
+                if $lowercase {digits.make_ascii_lowercase();}
+                f.pad_integral(self.sign == Sign::Positive, $prefix, &digits)
+            }
+        }
+    )+)
+}
+impl_fmt! {
+    Display 10 "" false
+    Binary 2 "0b" false
+    Octal 8 "0o" false
+    LowerHex 16 "0x" true
+    UpperHex 16 "0x" false
+}
@@ -0,0 +1,67 @@
+use super::super::LBInt;
+use crate::{
+    linkedbytes::{LBNum, TryFromIntError},
+    Sign,
+};
+use core::convert::TryFrom;
+
+macro_rules! impl_from_unsigned {
+    ($($ty:ident)+) => ($(
+        impl From<$ty> for LBInt {
+            #[inline(always)]
+            fn from(op: $ty) -> Self {
+                Self::new(Sign::Positive, LBNum::from(op))
+            }
+        }
+        impl TryFrom<&LBInt> for $ty {
+            type Error = TryFromIntError;
+            #[inline]
+            fn try_from(op: &LBInt) -> Result<Self, TryFromIntError> {
+                if op.is_negative() {return Err(TryFromIntError);}
+                Self::try_from(&op.magnitude)
+            }
+        }
+        impl TryFrom<LBInt> for $ty {
+            type Error = TryFromIntError;
+            #[inline(always)]
+            fn try_from(op: LBInt) -> Result<Self, TryFromIntError> {
+                Self::try_from(&op)
+            }
+        }
+    )+)
+}
+macro_rules! impl_from_signed {
+    ($($ty:ident)+) => ($(
+        impl From<$ty> for LBInt {
+            #[inline(always)]
+            fn from(op: $ty) -> Self {
+                Self::new(Sign::from(op < 0), LBNum::from(op.unsigned_abs()))
+            }
+        }
+        impl TryFrom<&LBInt> for $ty {
+            type Error = TryFromIntError;
+            fn try_from(op: &LBInt) -> Result<Self, TryFromIntError> {
+                let magnitude = u128::try_from(&op.magnitude)?;
+                let value = match op.sign {
+                    Sign::Positive => i128::try_from(magnitude).map_err(|_| TryFromIntError)?,
+                    // i128::MIN has no positive counterpart, so the magnitude can't be converted to i128 before negating it.
+                    Sign::Negative => 0_i128.checked_sub_unsigned(magnitude).ok_or(TryFromIntError)?,
+                };
+                Self::try_from(value).map_err(|_| TryFromIntError)
+            }
+        }
+        impl TryFrom<LBInt> for $ty {
+            type Error = TryFromIntError;
+            #[inline(always)]
+            fn try_from(op: LBInt) -> Result<Self, TryFromIntError> {
+                Self::try_from(&op)
+            }
+        }
+    )+)
+}
+impl_from_unsigned! {
+    u8 u16 u32 u64 u128 usize
+}
+impl_from_signed! {
+    i8 i16 i32 i64 i128 isize
+}
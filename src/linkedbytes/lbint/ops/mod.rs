@@ -0,0 +1,116 @@
+use super::LBInt;
+use crate::{LBNum, Sign};
+use core::{
+    cmp::{PartialOrd, Ord, Ordering},
+    ops::Neg,
+};
+
+mod arith; mod from; mod fmt;
+#[cfg(feature = "num_traits")]
+mod num;
+
+impl PartialEq for LBInt {
+    #[inline(always)]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.sign == rhs.sign && self.magnitude == rhs.magnitude
+    }
+}
+impl Eq for LBInt {}
+impl PartialOrd for LBInt {
+    #[inline(always)]
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+impl Ord for LBInt {
+    #[inline]
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        match (self.sign, rhs.sign) {
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Positive) => self.magnitude.cmp(&rhs.magnitude),
+            // The larger the absolute value, the smaller the negative number.
+            (Sign::Negative, Sign::Negative) => rhs.magnitude.cmp(&self.magnitude),
+        }
+    }
+}
+
+impl LBInt {
+    /// Compares the number to a primitive integer, given as its sign and absolute value, without allocating.
+    fn cmp_to_primitive<T>(&self, sign: Sign, magnitude: &T) -> Ordering
+    where LBNum: PartialOrd<T> {
+        let cmp_magnitudes = || self.magnitude.partial_cmp(magnitude)
+            .expect("comparisons to primitives never fail");
+        match (self.sign, sign) {
+            (Sign::Positive, Sign::Negative) => Ordering::Greater,
+            (Sign::Negative, Sign::Positive) => Ordering::Less,
+            (Sign::Positive, Sign::Positive) => cmp_magnitudes(),
+            (Sign::Negative, Sign::Negative) => cmp_magnitudes().reverse(),
+        }
+    }
+}
+
+macro_rules! impl_compare_to_primitive {
+    ($($ty:ident)+, |$op:ident| $parts:expr) => ($(
+        impl PartialEq<$ty> for LBInt {
+            #[inline(always)]
+            fn eq(&self, rhs: &$ty) -> bool {
+                self.partial_cmp(rhs) == Some(Ordering::Equal)
+            }
+        }
+        impl PartialEq<LBInt> for $ty {
+            #[inline(always)]
+            fn eq(&self, rhs: &LBInt) -> bool {
+                rhs == self
+            }
+        }
+        impl PartialOrd<$ty> for LBInt {
+            /// Compares `self` and `rhs`.
+            ///
+            /// Never fails, a return value of `Some` can be relied upon.
+            #[inline]
+            fn partial_cmp(&self, rhs: &$ty) -> Option<Ordering> {
+                let $op = *rhs;
+                let (sign, magnitude) = $parts;
+                Some(self.cmp_to_primitive(sign, &magnitude))
+            }
+        }
+        impl PartialOrd<LBInt> for $ty {
+            /// Compares `self` and `rhs`.
+            ///
+            /// Never fails, a return value of `Some` can be relied upon.
+            #[inline(always)]
+            fn partial_cmp(&self, rhs: &LBInt) -> Option<Ordering> {
+                rhs.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    )+)
+}
+
+impl_compare_to_primitive! {
+    u8 u16 u32 u64 u128 usize,
+    |op| (Sign::Positive, op)
+}
+impl_compare_to_primitive! {
+    i8 i16 i32 i64 i128 isize,
+    |op| (if op < 0 {Sign::Negative} else {Sign::Positive}, op.unsigned_abs())
+}
+
+impl Neg for LBInt {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        let sign = match self.sign {
+            Sign::Positive => Sign::Negative,
+            Sign::Negative => Sign::Positive,
+        };
+        Self::new(sign, self.magnitude)
+    }
+}
+impl Neg for &LBInt {
+    type Output = LBInt;
+    #[inline(always)]
+    fn neg(self) -> LBInt {
+        -self.clone()
+    }
+}
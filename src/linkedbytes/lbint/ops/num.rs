@@ -0,0 +1,58 @@
+//! Implementations of the traits from `num-traits`.
+
+use super::super::LBInt;
+use crate::{
+    linkedbytes::{LBNum, ParseLBNumError},
+    Sign,
+};
+use num_traits::{Zero, One, Num, Signed};
+
+impl Zero for LBInt {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.magnitude.num_bytes() == 0
+    }
+}
+impl One for LBInt {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::from(1_u8)
+    }
+}
+impl Num for LBInt {
+    type FromStrRadixErr = ParseLBNumError;
+    #[inline(always)]
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseLBNumError> {
+        Self::from_str_radix(src, radix)
+    }
+}
+impl Signed for LBInt {
+    #[inline(always)]
+    fn abs(&self) -> Self {
+        Self::from(self.magnitude.clone())
+    }
+    #[inline]
+    fn abs_sub(&self, rhs: &Self) -> Self {
+        if self <= rhs {Self::ZERO} else {self - rhs}
+    }
+    #[inline]
+    fn signum(&self) -> Self {
+        if self.magnitude.num_bytes() == 0 {
+            Self::ZERO
+        } else {
+            Self::new(self.sign, LBNum::from(1_u8))
+        }
+    }
+    #[inline(always)]
+    fn is_positive(&self) -> bool {
+        self.is_positive()
+    }
+    #[inline(always)]
+    fn is_negative(&self) -> bool {
+        self.sign == Sign::Negative
+    }
+}
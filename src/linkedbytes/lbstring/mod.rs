@@ -1,11 +1,19 @@
 //! A Unicode string format implemented using Linked Bytes.
 //!
-//! This module is the home for [`LBString`][lbs] and [`LBCharsIter`][lbci], which implement the Linked Bytes string storage format, as seen in the official specification. This is, perhaps, the most widely used feature of BigBit, since it's useful even when you don't need the powerful big number storage. The documentation on the `LBString` page elaborates on that.
+//! This module is the home for [`LBString`][lbs] and [`LBCharsIter`][lbci], along with [`LBPattern`][lbp] and [`LBSplit`][lbsp] used for searching, which implement the Linked Bytes string storage format, as seen in the official specification. This is, perhaps, the most widely used feature of BigBit, since it's useful even when you don't need the powerful big number storage. The documentation on the `LBString` page elaborates on that.
 //!
 //! [lbs]: struct.LBString.html "LBString — a Unicode string stored using the Linked Bytes format"
 //! [lbci]: struct.LBCharsIter.html "LBCharsIter — an iterator over the codepoints in an LBString"
+//! [lbp]: trait.LBPattern.html "LBPattern — a pattern which can be searched for in an LBString"
+//! [lbsp]: struct.LBSplit.html "LBSplit — an iterator over the parts of an LBString separated by a pattern"
 
-use super::{LBNum, LBSequence, LBNumRef};
+mod ops; pub use ops::{LBPattern, LBSplit};
+
+use super::{LBSequence, LBNumRef, LinkedByte};
+use core::{
+    ops::{Bound, Range, RangeBounds},
+    hint::unreachable_unchecked,
+};
 
 /// A Unicode string stored using the Linked Bytes format.
 ///
@@ -28,9 +36,30 @@ use super::{LBNum, LBSequence, LBNumRef};
 /// # assert_eq!(String::from(stdstring_lb), "This is a standard string!");
 /// # assert_eq!(String::from(my_string_lb_2), MY_STRING);
 /// ```
+///
+/// Editing and searching work on the Linked Bytes encoding directly, without decoding the entire string. All indices are **codepoint** indices:
+/// ```
+/// # use bigbit::LBString;
+/// let mut string = LBString::from("Hello");
+/// string.push_str(", world");
+/// string.push('!');
+/// string.insert(0, '¡');
+/// assert_eq!(string, "¡Hello, world!");
+/// assert_eq!(string.find("world"), Some(8));
+/// assert_eq!(string.slice(1..6), "Hello");
+/// assert_eq!(string.remove(0), '¡');
+/// assert!(string.split(", ").eq(["Hello", "world!"].iter().copied().map(LBString::from)));
+/// ```
 #[derive(Clone, Debug)]
 pub struct LBString(LBSequence);
 impl LBString {
+    /// Creates an empty string.
+    ///
+    /// This does not allocate memory.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self(LBSequence::empty())
+    }
     /// Returns an iterator over the codepoints in the string.
     ///
     /// This is the core method of this type. Most other methods use this to perform more complex operations, such as conversion from an `&str`.
@@ -41,10 +70,10 @@ impl LBString {
 
     /// Counts the number of **codepoints** stored.
     ///
-    /// This will iterate through the entire string and count how many codepoints were resolved successfully. Currently, this is implemented as simply `self.chars().count()`.
-    #[inline(always)]
+    /// This will iterate through the entire string, counting the endpoint bytes, since every codepoint ends with exactly one of them. The codepoints themselves are not decoded.
+    #[inline]
     pub fn len(&self) -> usize {
-        self.chars().count()
+        self.0.iter_le().filter(|byte| byte.is_end()).count()
     }
     /// Returns `true` if there are no codepoints stored, `false` otherwise.
     #[inline(always)]
@@ -56,17 +85,68 @@ impl LBString {
     pub const fn inner(&self) -> &LBSequence {
         &self.0
     }
+
+    /// Returns a copy of the codepoints in the specified range, or `None` if the range is out of bounds or decreasing.
+    pub fn checked_slice(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let range = self.byte_range(range)?;
+        Some(Self(LBSequence::from(&self.0.inner()[range])))
+    }
+    /// Returns a copy of the codepoints in the specified range.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or decreasing. See [`checked_slice`][0] for a version which returns `None` instead.
+    ///
+    /// [0]: #method.checked_slice "checked_slice — returns a copy of the codepoints in the specified range, or None if it's out of bounds"
+    #[inline]
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        self.checked_slice(range).expect("codepoint range out of bounds")
+    }
+
+    /// Returns the byte offset of the codepoint at `index`, counting from the codepoint which starts at the byte offset `from`, or `None` if there aren't enough codepoints. The length of the string is a valid index, and produces the offset past the last byte.
+    fn byte_offset(&self, from: usize, index: usize) -> Option<usize> {
+        if index == 0 {return Some(from);}
+        self.0.inner()[from..].iter()
+            .enumerate()
+            .filter(|(_, byte)| byte.is_end())
+            .nth(index - 1)
+            .map(|(offset, _)| from + offset + 1)
+    }
+    /// Converts a range of codepoint indices into a range of byte offsets, or returns `None` if it's out of bounds or decreasing.
+    fn byte_range(&self, range: impl RangeBounds<usize>) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let start_offset = self.byte_offset(0, start)?;
+        let end_offset = match range.end_bound() {
+            Bound::Included(&end) => self.byte_offset(start_offset, end.checked_add(1)?.checked_sub(start)?)?,
+            Bound::Excluded(&end) => self.byte_offset(start_offset, end.checked_sub(start)?)?,
+            Bound::Unbounded => self.0.len(),
+        };
+        Some(start_offset..end_offset)
+    }
+    /// Returns the number of codepoints which end before the specified byte offset, i.e. the codepoint index of the byte offset.
+    fn codepoint_index(&self, offset: usize) -> usize {
+        self.0.inner()[..offset].iter().filter(|byte| byte.is_end()).count()
+    }
+    /// Checks whether a codepoint starts at the specified byte offset, which includes the offset past the last byte.
+    fn is_boundary(&self, offset: usize) -> bool {
+        offset == 0 || self.0.inner()[offset - 1].is_end()
+    }
+}
+impl Default for LBString {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
 }
 impl core::iter::FromIterator<char> for LBString {
+    #[inline]
     fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
-        let mut result = Self(LBSequence::empty());
-        let mut lbn = LBNum::ZERO;
-        for c in iter {
-            lbn.make_zero(); // This is a specialized method for making the value zero without reallocating,
-                             // which makes it vital for larger strings.
-            lbn += u32::from(c);
-            result.0.inner_mut().extend(lbn.iter_le());
-        }
+        let mut result = Self::new();
+        result.extend(iter);
         result
     }
 }
@@ -81,11 +161,33 @@ impl core::fmt::Display for LBString {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         use core::fmt::Write;
         for c in self.chars() {
-            if let Err(e) = f.write_char(c) {return Err(e);} // Stop right where we are if we can't write anything.
+            f.write_char(c)?; // Stop right where we are if we can't write anything.
         }
         Ok(())
     }
 }
+/// Encodes a codepoint using Linked Bytes, returning the bytes along with how many of them are used, which is 3 at most, since codepoints never exceed 21 bits.
+///
+/// Unlike a Linked Bytes number, which is empty when it's zero, the NUL codepoint takes one zero byte, since it has to be delimited from the other codepoints.
+fn encode_char(c: char) -> ([LinkedByte; 3], usize) {
+    let mut codepoint = u32::from(c);
+    let mut result = [LinkedByte::ZERO_END; 3];
+    let mut len = 0;
+    loop {
+        let digit = (codepoint & 0x7F) as u8;
+        codepoint >>= 7;
+        result[len] = LinkedByte::from((digit, codepoint != 0));
+        len += 1;
+        if codepoint == 0 {break (result, len);}
+    }
+}
+/// Decodes a single codepoint from its Linked Bytes encoding.
+fn decode_char(bytes: &[LinkedByte]) -> char {
+    let codepoint = bytes.iter().rev().fold(0_u32, |codepoint, byte| (codepoint << 7) | u32::from(byte.value()));
+    char::from_u32(codepoint)
+        .unwrap_or_else(|| unsafe {unreachable_unchecked()}) // Value validity is a safety guarantee for LBString, see LBCharsIter.
+}
+
 /// An iterator over the codepoints in an `LBString`.
 ///
 /// This resolves the codepoints on the fly, as all lazy iterators do. Thus creating such an iterator is totally free.
@@ -103,7 +205,7 @@ impl<'a> LBCharsIter<'a> {
 impl<'a> Iterator for LBCharsIter<'a> {
     type Item = char;
     fn next(&mut self) -> Option<char> { // If anything breaks, blame this tymethod (seriously, please do).
-        use core::{convert::TryInto, hint::unreachable_unchecked};
+        use core::convert::TryInto;
         let mut chosen_range = self.index..self.index;
         loop {
             if let Some(v) = self.inner.inner().get(self.index) {
@@ -0,0 +1,75 @@
+#![cfg_attr(feature = "clippy", allow(clippy::use_self))]
+
+use crate::linkedbytes::{LBSequence, lbstring::LBString};
+use core::ops::{Add, AddAssign};
+use alloc::vec::Vec;
+
+impl LBString {
+    /// Concatenates multiple strings into a new one.
+    ///
+    /// The encoded strings are simply copied one after another, since every codepoint is self-delimiting.
+    pub fn concat<'a>(strings: impl IntoIterator<Item = &'a Self>) -> Self {
+        let mut result = Self::new();
+        for string in strings {
+            result += string;
+        }
+        result
+    }
+}
+
+impl AddAssign<&LBString> for LBString {
+    #[inline]
+    fn add_assign(&mut self, rhs: &LBString) {
+        self.0.inner_mut().extend_from_slice(rhs.0.inner());
+    }
+}
+impl AddAssign<LBString> for LBString {
+    #[inline]
+    fn add_assign(&mut self, rhs: LBString) {
+        if self.is_empty() {
+            *self = rhs; // Reuse the allocation of the right operand if we don't have anything yet.
+        } else {
+            *self += &rhs;
+        }
+    }
+}
+impl AddAssign<&str> for LBString {
+    #[inline(always)]
+    fn add_assign(&mut self, rhs: &str) {
+        self.push_str(rhs);
+    }
+}
+impl Add<&LBString> for LBString {
+    type Output = Self;
+    #[inline(always)]
+    fn add(mut self, rhs: &LBString) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl Add<LBString> for LBString {
+    type Output = Self;
+    #[inline(always)]
+    fn add(mut self, rhs: LBString) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl Add<&str> for LBString {
+    type Output = Self;
+    #[inline(always)]
+    fn add(mut self, rhs: &str) -> Self {
+        self += rhs;
+        self
+    }
+}
+impl Add<&LBString> for &LBString {
+    type Output = LBString;
+    #[inline]
+    fn add(self, rhs: &LBString) -> LBString {
+        let mut result = Vec::with_capacity(self.0.len() + rhs.0.len());
+        result.extend_from_slice(self.0.inner());
+        result.extend_from_slice(rhs.0.inner());
+        LBString(LBSequence::from(result))
+    }
+}
@@ -78,4 +78,16 @@ macro_rules! impl_pcmp_for_chars {
 }
 
 impl_pcmp_for_chars!(String);
-impl_pcmp_for_chars!(str);
\ No newline at end of file
+impl_pcmp_for_chars!(str);
+impl<'a> PartialEq<&'a str> for LBString {
+    #[inline(always)]
+    fn eq(&self, rhs: &&'a str) -> bool {
+        self == *rhs
+    }
+}
+impl PartialEq<LBString> for &str {
+    #[inline(always)]
+    fn eq(&self, rhs: &LBString) -> bool {
+        rhs == *self
+    }
+}
@@ -0,0 +1,87 @@
+use crate::linkedbytes::lbstring::{LBString, encode_char, decode_char};
+
+impl LBString {
+    /// Appends a codepoint to the end of the string.
+    #[inline]
+    pub fn push(&mut self, c: char) {
+        let (bytes, len) = encode_char(c);
+        self.0.inner_mut().extend_from_slice(&bytes[..len]);
+    }
+    /// Appends a string slice to the end of the string.
+    #[inline(always)]
+    pub fn push_str(&mut self, op: &str) {
+        self.extend(op.chars());
+    }
+    /// Removes the last codepoint from the string and returns it, or returns `None` if the string is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let inner = self.0.inner_mut();
+        let (_, rest) = inner.split_last()?;
+        // The last codepoint starts right after the second to last endpoint byte.
+        let start = rest.iter().rposition(|byte| byte.is_end()).map_or(0, |index| index + 1);
+        let c = decode_char(&inner[start..]);
+        inner.truncate(start);
+        Some(c)
+    }
+    /// Inserts a codepoint at the specified codepoint index, shifting all codepoints after it to the right.
+    ///
+    /// # Panics
+    /// Panics if the index is larger than the length of the string.
+    pub fn insert(&mut self, index: usize, c: char) {
+        let offset = self.byte_offset(0, index).expect("insertion index out of bounds");
+        let (bytes, len) = encode_char(c);
+        self.0.inner_mut().splice(offset..offset, bytes[..len].iter().copied());
+    }
+    /// Inserts a string slice at the specified codepoint index, shifting all codepoints after it to the right.
+    ///
+    /// # Panics
+    /// Panics if the index is larger than the length of the string.
+    pub fn insert_str(&mut self, index: usize, op: &str) {
+        let offset = self.byte_offset(0, index).expect("insertion index out of bounds");
+        let inserted = Self::from(op);
+        self.0.inner_mut().splice(offset..offset, inserted.0.iter_le());
+    }
+    /// Removes the codepoint at the specified codepoint index and returns it, shifting all codepoints after it to the left.
+    ///
+    /// # Panics
+    /// Panics if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> char {
+        let range = self.byte_range(index..=index)
+            .filter(|range| !range.is_empty())
+            .expect("removal index out of bounds");
+        let c = decode_char(&self.0.inner()[range.clone()]);
+        self.0.inner_mut().drain(range);
+        c
+    }
+    /// Shortens the string to the specified number of codepoints.
+    ///
+    /// Has no effect if the string is already shorter than that.
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(offset) = self.byte_offset(0, len) {
+            self.0.inner_mut().truncate(offset);
+        }
+    }
+    /// Removes all codepoints from the string, keeping the allocated memory.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.0.inner_mut().clear();
+    }
+}
+
+impl Extend<char> for LBString {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        // Every codepoint takes at least one byte.
+        self.0.inner_mut().reserve(iter.size_hint().0);
+        for c in iter {
+            self.push(c);
+        }
+    }
+}
+impl<'a> Extend<&'a char> for LBString {
+    /// Convenience implementation for collections which iterate over references to items rather than the items themselves, to avoid repetitive `.copied()` in calling code.
+    #[inline(always)]
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
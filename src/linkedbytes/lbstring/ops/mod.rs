@@ -1,3 +1,6 @@
-mod from; mod into; mod cmp;
+mod from; mod into; mod cmp; mod edit; mod search; mod add;
+#[cfg(feature = "serde")]
+mod serde;
 #[allow(unused_imports)]
-pub(crate) use {from::*, into::*, cmp::*};
\ No newline at end of file
+pub(crate) use {from::*, into::*, cmp::*};
+pub use search::{LBPattern, LBSplit};
\ No newline at end of file
@@ -0,0 +1,131 @@
+use crate::{
+    linkedbytes::{LBSequence, LinkedByte, lbstring::{LBString, encode_char}},
+    Sealed,
+};
+use core::iter::FusedIterator;
+use alloc::{string::String, vec::Vec};
+
+/// A pattern which can be searched for in an [`LBString`][lbs]: a codepoint, a string slice, a `String` or another `LBString`.
+///
+/// Patterns are matched by comparing their Linked Bytes encoding to the encoding of the string, which means that searching never decodes the string. The only extra check is whether the match starts at a codepoint boundary, since the end of the pattern always is one.
+///
+/// This trait is **sealed**, i.e. cannot be implemented for types outside of the `bigbit` crate, which allows adding new methods to the trait without breaking changes and prevents logic errors.
+///
+/// [lbs]: struct.LBString.html "LBString — a Unicode string stored using the Linked Bytes format"
+pub trait LBPattern: Sealed {
+    /// Calls the closure with the Linked Bytes encoding of the pattern.
+    fn with_encoding<T>(self, f: impl FnOnce(&[LinkedByte]) -> T) -> T;
+}
+impl LBPattern for char {
+    #[inline]
+    fn with_encoding<T>(self, f: impl FnOnce(&[LinkedByte]) -> T) -> T {
+        let (bytes, len) = encode_char(self);
+        f(&bytes[..len])
+    }
+}
+impl LBPattern for &str {
+    #[inline]
+    fn with_encoding<T>(self, f: impl FnOnce(&[LinkedByte]) -> T) -> T {
+        f(LBString::from(self).0.inner())
+    }
+}
+impl LBPattern for &String {
+    #[inline(always)]
+    fn with_encoding<T>(self, f: impl FnOnce(&[LinkedByte]) -> T) -> T {
+        self.as_str().with_encoding(f)
+    }
+}
+impl LBPattern for &LBString {
+    #[inline(always)]
+    fn with_encoding<T>(self, f: impl FnOnce(&[LinkedByte]) -> T) -> T {
+        f(self.0.inner())
+    }
+}
+
+impl LBString {
+    /// Returns the codepoint index of the first match of the pattern, or `None` if there is none.
+    ///
+    /// An empty pattern matches at index 0.
+    #[inline]
+    pub fn find<P: LBPattern>(&self, pattern: P) -> Option<usize> {
+        let offset = pattern.with_encoding(|needle| self.find_bytes(needle, 0))?;
+        Some(self.codepoint_index(offset))
+    }
+    /// Returns `true` if the pattern matches anywhere in the string, `false` otherwise.
+    #[inline]
+    pub fn contains<P: LBPattern>(&self, pattern: P) -> bool {
+        pattern.with_encoding(|needle| self.find_bytes(needle, 0)).is_some()
+    }
+    /// Returns `true` if the string starts with the pattern, `false` otherwise.
+    #[inline]
+    pub fn starts_with<P: LBPattern>(&self, pattern: P) -> bool {
+        pattern.with_encoding(|needle| self.0.inner().starts_with(needle))
+    }
+    /// Returns `true` if the string ends with the pattern, `false` otherwise.
+    #[inline]
+    pub fn ends_with<P: LBPattern>(&self, pattern: P) -> bool {
+        pattern.with_encoding(|needle| {
+            self.0.inner().ends_with(needle) && self.is_boundary(self.0.len() - needle.len())
+        })
+    }
+    /// Returns an iterator over the parts of the string separated by the pattern.
+    ///
+    /// Just like with `str::split`, adjacent matches produce empty parts, and so do matches at the start or the end of the string. An empty pattern matches before and after every codepoint.
+    #[inline]
+    pub fn split<P: LBPattern>(&self, pattern: P) -> LBSplit<'_> {
+        LBSplit {
+            string: self,
+            needle: pattern.with_encoding(<[LinkedByte]>::to_vec),
+            start: 0,
+            search_from: 0,
+            finished: false,
+        }
+    }
+
+    /// Returns the byte offset of the first match of an encoded pattern which starts at a codepoint boundary at or after the byte offset `from`.
+    fn find_bytes(&self, needle: &[LinkedByte], from: usize) -> Option<usize> {
+        let haystack = self.0.inner();
+        if from > haystack.len() {return None;}
+        if needle.is_empty() {
+            return (from..=haystack.len()).find(|&offset| self.is_boundary(offset));
+        }
+        haystack.windows(needle.len())
+            .enumerate()
+            .skip(from)
+            .find(|&(offset, window)| window == needle && self.is_boundary(offset))
+            .map(|(offset, _)| offset)
+    }
+}
+
+/// An iterator over the parts of an `LBString` separated by a pattern, created by the [`split`][0] method.
+///
+/// [0]: struct.LBString.html#method.split "split — returns an iterator over the parts of the string separated by a pattern"
+#[derive(Clone, Debug)]
+pub struct LBSplit<'a> {
+    string: &'a LBString,
+    needle: Vec<LinkedByte>,
+    /// The byte offset at which the next part starts.
+    start: usize,
+    /// The byte offset at which to search for the next match, which is after the start of the next part for empty patterns, to avoid matching at the same offset forever.
+    search_from: usize,
+    finished: bool,
+}
+impl Iterator for LBSplit<'_> {
+    type Item = LBString;
+    fn next(&mut self) -> Option<LBString> {
+        if self.finished {return None;}
+        let len = self.string.0.len();
+        let (end, next_start) = if let Some(offset) = self.string.find_bytes(&self.needle, self.search_from) {
+            let next_start = offset + self.needle.len();
+            self.search_from = if self.needle.is_empty() {offset + 1} else {next_start};
+            (offset, next_start)
+        } else {
+            self.finished = true;
+            (len, len)
+        };
+        let part = LBString(LBSequence::from(&self.string.0.inner()[self.start..end]));
+        self.start = next_start;
+        Some(part)
+    }
+}
+impl FusedIterator for LBSplit<'_> {}
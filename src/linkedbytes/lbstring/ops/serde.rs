@@ -0,0 +1,58 @@
+//! Implementations of the `Serialize` and `Deserialize` traits from `serde`.
+//!
+//! Human-readable formats get the string as is, while binary formats get the Linked Bytes encoding as a byte array.
+
+use crate::{LBNum, LBString, LinkedByte, serde_bytes::visit_byte_seq};
+use core::{convert::TryFrom, fmt::{self, Formatter}};
+use alloc::vec::Vec;
+use serde::{
+    Serialize, Serializer, Deserialize, Deserializer,
+    de::{self, Visitor, SeqAccess, Unexpected},
+};
+
+impl Serialize for LBString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let bytes = self.inner().iter_le().map(LinkedByte::into_inner).collect::<Vec<_>>();
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+impl<'de> Deserialize<'de> for LBString {
+    /// Deserializes a string as is in human-readable formats and from its Linked Bytes encoding otherwise.
+    ///
+    /// Every codepoint in the encoding has to be a valid Unicode scalar value.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(LBStringVisitor)
+        } else {
+            deserializer.deserialize_bytes(LBStringVisitor)
+        }
+    }
+}
+
+struct LBStringVisitor;
+impl<'de> Visitor<'de> for LBStringVisitor {
+    type Value = LBString;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a Linked Bytes string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<LBString, E> {
+        Ok(LBString::from(v))
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<LBString, E> {
+        LBNum::decode_iter(v)
+            .map(|codepoint| {
+                let codepoint = u32::try_from(codepoint.ok()?).ok()?;
+                char::from_u32(codepoint)
+            })
+            .collect::<Option<LBString>>()
+            .ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<LBString, A::Error> {
+        self.visit_bytes(&visit_byte_seq(seq)?)
+    }
+}
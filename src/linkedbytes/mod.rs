@@ -1,9 +1,16 @@
 //! The Linked Bytes format, capable of storing arbitrarily large unsigned integers, also used to efficently store Unicode strings.
 //!
-//! If you only want non-negative integers, you should stick to this format. (Signed LB integers are also planned.) Otherwise, use either Head Byte or Extended Head Byte.
+//! If you only want integers, you should stick to this format — [`LBInt`][lbi] stores signed ones on top of [`LBNum`][lbn]. Otherwise, use either Head Byte or Extended Head Byte.
+//!
+//! [lbi]: lbint/struct.LBInt.html "LBInt — a signed integer stored using the Linked Bytes format"
+//! [lbn]: struct.LBNum.html "LBNum — a number in the Linked Bytes format, capable of storing arbitrarily large non-negative integers"
 
 pub mod lbstring; pub use lbstring::LBString;
-mod ops;
+pub mod lbint; pub use lbint::LBInt;
+mod codec; pub use codec::{LBDecodeIter, LBRefDecodeIter};
+mod ops; pub use ops::{TryFromIntError, ParseLBNumError, ParseLBNumErrorKind};
+#[cfg(feature = "rand")]
+pub mod random;
 
 use core::{
     slice::SliceIndex,
@@ -61,24 +68,21 @@ impl LBNum {
     /// Decrements the value, returning `true` if the decrement did anything and `false` if `self` was zero.
     #[inline(always)]
     pub fn checked_decrement(&mut self) -> bool {
-        use ops::DecrementResult;
-        match self.decrement_at_index(0) {
-            DecrementResult::EndedWithBorrow | DecrementResult::NoSuchIndex => false,
-            DecrementResult::Ok(_) => true
-        }
+        !self.decrement_at_index(0)
     }
-    /// Consumes `self` and returns the logarithm of the given base.
+    /// Consumes `self` and returns the logarithm of the given base, rounded down. The logarithm of zero is zero.
+    ///
+    /// # Panics
+    /// Panics if the base is less than 2. Use [`checked_ilog`][0], which also doesn't consume the number, to handle that.
+    ///
+    /// [0]: #method.checked_ilog "checked_ilog — returns the logarithm of the number with respect to an arbitrary base, rounded down"
     #[inline]
     #[must_use = "this is an expensive non-in-place operation"]
-    pub fn logb(mut self, base: Self) -> Self {
-        let mut result = Self::ZERO;
-        let divide_by = base.clone();
-        let base_m1: Self = base - 1_u8;
-        while self > base_m1 {
-            self /= &divide_by;
-            result.increment();
-        }
-        result
+    #[deprecated(note = "use checked_ilog instead")]
+    #[cfg_attr(feature = "clippy", allow(clippy::needless_pass_by_value))] // The signature is kept as is for compatibility.
+    pub fn logb(self, base: Self) -> Self {
+        assert!(base >= 2_u8, "the base of a logarithm must be at least 2");
+        Self::from(self.checked_ilog(&base).unwrap_or(0))
     }
     /// Returns an immutable reference to the inner sequence.
     ///
@@ -95,12 +99,12 @@ impl LBNum {
     }
     /// Returns an iterator over the linked bytes, in **little**-endian byte order.
     #[inline(always)]
-    pub fn iter_le(&self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_le(&self) -> impl DoubleEndedIterator<Item = LinkedByte> + '_ {
         self.0.iter_le()
     }
     /// Returns an iterator over the linked bytes, in **big**-endian byte order.
     #[inline(always)]
-    pub fn iter_be(&self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_be(&self) -> impl DoubleEndedIterator<Item = LinkedByte> + '_ {
         self.0.iter_be()
     }
 
@@ -109,7 +113,7 @@ impl LBNum {
     /// If any of the bytes except for the last one are endpoints (most significant bit cleared), they are converted into linked (most significant bit set), and if the last byte is linked, it's converted into and endpoint.
     #[inline(always)]
     pub fn from_sequence(mut op: LBSequence) -> Self {
-        Self::fix_in_place(&mut op.inner_mut());
+        Self::fix_in_place(op.inner_mut());
         Self(op)
     }
 
@@ -119,19 +123,6 @@ impl LBNum {
         LBNumRef::from(self)
     }
 
-    /// Ensures that the last element is an endpoint.
-    pub(crate) fn ensure_last_is_end(&mut self) {
-        if let Some(last) = self.0.inner_mut().last_mut() {
-            *last = last.into_end()
-        } else {}
-    }
-    /// Converts the last element to a linked byte, for adding together two `LBNum`s.
-    pub(crate) fn convert_last_to_linked(&mut self) {
-        if let Some(last) = self.0.inner_mut().last_mut() {
-            *last = last.into_linked()
-        } else {}
-    }
-
     /// Checks whether the operand is a compliant LB sequence.
     ///
     /// See [`InvalidLBSequence`][0] for reasons why it might not be compliant.
@@ -151,6 +142,20 @@ impl LBNum {
         }
         true // ok we're fine
     }
+    /// Converts the number into its canonical form, without changing its value.
+    ///
+    /// In the canonical form, all bytes except for the last one are linked, the last one is an endpoint and there are no redundant zero bytes at the most significant end. Every number produced by arithmetic and conversions is already in the canonical form, but numbers created from raw sequences might not be.
+    #[inline(always)]
+    pub fn normalize(&mut self) {
+        self.zero_fold();
+    }
+    /// Returns `true` if the number is in its canonical form, as described in [`normalize`][0].
+    ///
+    /// [0]: #method.normalize "normalize — converts the number into its canonical form"
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        Self::check_slice(self.0.inner()) && self.0.inner().last().map_or(true, |last| last.value() != 0)
+    }
     /// Makes a slice of `LinkedByte`s suitable for storage in a `HBNum` by marking the last byte as an endpoint and the rest as linked ones.
     pub fn fix_in_place(op: &mut [LinkedByte]) {
         if let Some(last) = op.last_mut() {
@@ -166,17 +171,87 @@ impl LBNum {
         }
     }
 
-    /// Removes trailing zeros.
-    pub(crate) fn zero_fold(&mut self) {
-        for i in (0..self.num_bytes()).rev() {
-            if self.0.inner()[i].into_end() == LinkedByte::ZERO_END {
-                self.0.inner_mut().pop();
+    /// Creates a number from its base-128 digits, given in little-endian order. Redundant zeros at the most significant end are allowed and removed.
+    pub(crate) fn from_digits(digits: impl IntoIterator<Item = u8>) -> Self {
+        let mut result = Self(digits.into_iter().map(LinkedByte::from).collect());
+        result.zero_fold();
+        result
+    }
+    /// Returns an iterator over the base-128 digits of the number, i.e. the values of its linked bytes, in little-endian order.
+    #[inline(always)]
+    pub(crate) fn digits(&self) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator + '_ {
+        self.0.inner().iter().map(|byte| byte.value())
+    }
+
+    /// Creates a number from a little-endian sequence of base-256 bytes, i.e. the coefficient storage of the Head Byte formats.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // The accumulator holds less than 8 bits when it's cast.
+    pub(crate) fn from_base256_le(bytes: &[u8]) -> Self {
+        let mut digits = Vec::with_capacity(bytes.len() * 8 / 7 + 1);
+        let (mut accumulator, mut bits) = (0_u16, 0_u32);
+        for &byte in bytes {
+            accumulator |= (byte as u16) << bits;
+            bits += 8;
+            while bits >= 7 {
+                digits.push((accumulator & 0x7F) as u8);
+                accumulator >>= 7;
+                bits -= 7;
+            }
+        }
+        digits.push(accumulator as u8);
+        Self::from_digits(digits)
+    }
+    /// Calculates 10 raised to the specified power.
+    pub(crate) fn pow10(mut exponent: u32) -> Self {
+        let mut result = Self::from(1_u8);
+        while exponent != 0 {
+            // 10¹⁹ is the largest power of 10 which fits into u64.
+            let step = exponent.min(19);
+            result *= 10_u64.pow(step);
+            exponent -= step;
+        }
+        result
+    }
+    /// Returns the most significant bits of the number as `top`, along with `exp2` such that the number is approximately `top × 2^exp2`. If any of the discarded bits are nonzero, the lowest bit of `top` is set, which allows correct rounding into floats.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_wrap))] // A number with 2⁶³ digits can't fit into memory.
+    pub(crate) fn top_bits(&self) -> (u128, i64) {
+        // 18 digits are 126 bits, which is more than enough for any float.
+        const TOP_DIGITS: usize = 18;
+        let low_digits = self.num_bytes().saturating_sub(TOP_DIGITS);
+        let mut top = self.digits().rev().take(TOP_DIGITS)
+            .fold(0_u128, |top, digit| (top << 7) | digit as u128);
+        if self.digits().take(low_digits).any(|digit| digit != 0) {
+            top |= 1;
+        }
+        (top, low_digits as i64 * 7)
+    }
+    /// Converts the number into a little-endian sequence of base-256 bytes without zeros at the most significant end, i.e. the coefficient storage of the Head Byte formats.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // Only the low 8 bits of the accumulator are taken, the rest is shifted out afterwards.
+    pub(crate) fn to_base256_le(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.num_bytes() * 7 / 8 + 1);
+        let (mut accumulator, mut bits) = (0_u16, 0_u32);
+        for digit in self.digits() {
+            accumulator |= (digit as u16) << bits;
+            bits += 7;
+            if bits >= 8 {
+                bytes.push(accumulator as u8);
+                accumulator >>= 8;
+                bits -= 8;
             }
         }
-        if self.num_bytes() != 0 {
-            let last_element_index = self.num_bytes() - 1;
-            self.0.inner_mut()[last_element_index].make_end();
+        bytes.push(accumulator as u8);
+        while bytes.last() == Some(&0) {bytes.pop();}
+        bytes
+    }
+
+    /// Removes trailing zeros.
+    ///
+    /// Also makes sure that the link bits are correct, since every arithmetic routine is expected to call this as its last step.
+    pub(crate) fn zero_fold(&mut self) {
+        while let Some(last) = self.0.inner().last() {
+            if last.value() != 0 {break;}
+            self.0.inner_mut().pop();
         }
+        Self::fix_in_place(self.0.inner_mut());
     }
 }
 impl core::convert::TryFrom<Vec<LinkedByte>> for LBNum {
@@ -261,12 +336,12 @@ impl<'a> LBNumRef<'a> {
     }
     /// Returns a by-value iterator over the linked bytes, **in little endian byte order.**
     #[inline(always)]
-    pub fn iter_le(self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + 'a {
+    pub fn iter_le(self) -> impl DoubleEndedIterator<Item = LinkedByte> + 'a {
         self.0.iter().copied()
     }
     /// Returns a by-value iterator over the linked bytes, **in big endian byte order.**
     #[inline(always)]
-    pub fn iter_be(self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + 'a {
+    pub fn iter_be(self) -> impl DoubleEndedIterator<Item = LinkedByte> + 'a {
         self.iter_le().rev()
     }
 
@@ -310,7 +385,7 @@ impl<'a> core::ops::Deref for LBNumRef<'a> {
 
 /// Marker error type representing that the decoder has encountered an invalid Linked Bytes sequence, created by the `TryFrom` implementation of `LBNum`.
 ///
-/// The only reason for this to ever happen is incorrect state of the link bit in one of the bytes: all the bytes except for the last one **have to be linked** (most significant bit set), and the last one **has to be an endpoint** (most significant bit clear).
+/// The only reason for this to ever happen is incorrect state of the link bit in one of the bytes: all the bytes except for the last one **have to be linked** (most significant bit set), and the last one **has to be an endpoint** (most significant bit clear). The strict decoding functions additionally produce this error for numbers which aren't in the canonical form, i.e. have redundant zero bytes at the most significant end.
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub struct InvalidLBSequence;
 
@@ -365,22 +440,22 @@ impl LBSequence {
 
     /// Returns an iterator over the linked bytes in **little**-endian byte order.
     #[inline(always)]
-    pub fn iter_le(&self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_le(&self) -> impl DoubleEndedIterator<Item = LinkedByte> + '_ {
         self.0.iter().copied()
     }
     /// Returns an iterator over the linked bytes in **big**-endian byte order.
     #[inline(always)]
-    pub fn iter_be(&self) -> impl Iterator<Item = LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_be(&self) -> impl DoubleEndedIterator<Item = LinkedByte> + '_ {
         self.iter_le().rev()
     }
     /// Returns an iterator over **mutable references** to the linked bytes in **little**-endian byte order.
     #[inline(always)]
-    pub fn iter_mut_le(&mut self) -> impl Iterator<Item = &mut LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_mut_le(&mut self) -> impl DoubleEndedIterator<Item = &mut LinkedByte> + '_ {
         self.0.iter_mut()
     }
     /// Returns an iterator over **mutable references** to the linked bytes in **big**-endian byte order.
     #[inline(always)]
-    pub fn iter_mut_be(&mut self) -> impl Iterator<Item = &mut LinkedByte> + DoubleEndedIterator + '_ {
+    pub fn iter_mut_be(&mut self) -> impl DoubleEndedIterator<Item = &mut LinkedByte> + '_ {
         self.iter_mut_le().rev()
     }
 }
@@ -508,7 +583,7 @@ impl LinkedByte {
     /// Performs checked addition. `None` is returned if the result overflows the limit of 127.
     #[inline]
     #[must_use = "this is not an in-place operation"]
-    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
         let (lhs_end, rhs_end) = (self.into_end().0, rhs.into_end().0);
         if let Some(nonwrapping) = lhs_end.checked_add(rhs_end) {
             if Self(nonwrapping).is_linked() {None} else {
@@ -523,7 +598,7 @@ impl LinkedByte {
     /// [ca]: #method.checked_add "checked_add — perform checked addition"
     #[inline]
     #[must_use = "this is not an in-place operation"]
-    pub fn add_with_carry(self, rhs: Self) -> (Self, bool) {
+    pub const fn add_with_carry(self, rhs: Self) -> (Self, bool) {
         if let Some(nonwrapping) = self.checked_add(rhs) {
             (nonwrapping, false)
         } else {
@@ -533,7 +608,7 @@ impl LinkedByte {
     /// Performs checked subtraction. `None` is returned if the result underflows the limit of 0.
     #[inline]
     #[must_use = "this is not an in-place operation"]
-    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
         let (lhs_end, rhs_end) = (self.into_end(), rhs.into_end());
         if let Some(nonwrapping) = lhs_end.0.checked_sub(rhs_end.0) {
             let mut result = Self(nonwrapping);
@@ -546,7 +621,7 @@ impl LinkedByte {
     /// [0]: #method.checked_sub "checked_sub — perform checked subtraction"
     #[inline]
     #[must_use = "this is not an in-place operation"]
-    pub fn sub_with_borrow(self, rhs: Self) -> (Self, bool) {
+    pub const fn sub_with_borrow(self, rhs: Self) -> (Self, bool) {
         if let Some(nonwrapping) = self.checked_sub(rhs) {
             (nonwrapping, false)
         } else {
@@ -4,38 +4,42 @@ use crate::{
     linkedbytes::{LBNum, LBNumRef, LinkedByte},
     AddAssignAt,
 };
+use super::u128_digits;
 use core::{
     ops::{Add, AddAssign},
-    hint,
+    iter,
     convert::TryInto,
 };
 
 impl LBNum {
-    /// Increments the byte at the specified index and returns whether wrapping occurred, or `None` if such an index does not exist.
-    pub(crate) fn increment_at_index(&mut self, index: usize) -> Option<bool> {
-        if self.0.is_empty() {
-            self.0.inner_mut().push(LinkedByte::from(1));
-            return Some(false);
-        }
-        if self.0.get(index).is_none() {
-            if self.0.get(index - 1).is_some() {
-                self.0.inner_mut()[index - 1].make_linked();
-                self.0.inner_mut().push(LinkedByte::ZERO_END);
-            } else {return None;}
-        }
-        for i in index.. {
-            if let Some(refindex) = self.0.get_mut(i) {
-                let (val, wrap) = refindex.add_with_carry(LinkedByte::from(1));
-                *refindex = val;
-                if !wrap {return Some(false);}
-            } else {
-                let len = self.0.inner().len();
-                self.0.inner_mut()[len - 1].make_linked();
-                self.0.inner_mut().push(LinkedByte::from(1).into_end());
-                return Some(true);
+    /// Adds a sequence of base-128 digits, given in little-endian order, to the number, with the least significant one of them landing at the specified digit index.
+    ///
+    /// This is the core of every addition implementation for Linked Bytes, including the ones with primitive integers.
+    pub(crate) fn add_digits_at(&mut self, index: usize, rhs: impl IntoIterator<Item = u8>) {
+        let inner = self.0.inner_mut();
+        let mut carry = 0_u16;
+        let mut rhs = rhs.into_iter();
+        let mut i = index;
+        loop {
+            let digit = match rhs.next() {
+                Some(digit) => digit,
+                None if carry != 0 => 0,
+                None => break,
+            };
+            if inner.len() <= i {
+                inner.resize(i + 1, LinkedByte::ZERO_LINK);
             }
+            let sum = inner[i].value() as u16 + digit as u16 + carry;
+            inner[i] = LinkedByte::from(((sum & 0x7F) as u8, true));
+            carry = sum >> 7;
+            i += 1;
         }
-        Some(true)
+        self.zero_fold();
+    }
+    /// Increments the digit at the specified index, carrying over into the higher digits if required.
+    #[inline(always)]
+    pub(crate) fn increment_at_index(&mut self, index: usize) {
+        self.add_digits_at(index, iter::once(1));
     }
 }
 
@@ -54,14 +58,14 @@ impl<'l, 'r> Add<LBNumRef<'r>> for LBNumRef<'l> {
     type Output = LBNum;
     #[inline(always)]
     fn add(self, rhs: LBNumRef<'r>) -> LBNum {
-        Add::add(self.to_owned(), rhs)
+        Add::add(self.into_owned(), rhs)
     }
 }
 impl<'l, 'r> Add<&'r LBNum> for LBNumRef<'l> {
     type Output = LBNum;
     #[inline(always)]
     fn add(self, rhs: &'r LBNum) -> LBNum {
-        Add::add(self.to_owned(), rhs.borrow())
+        Add::add(self.into_owned(), rhs.borrow())
     }
 }
 impl<'l> Add<LBNum> for LBNumRef<'l> {
@@ -72,21 +76,21 @@ impl<'l> Add<LBNum> for LBNumRef<'l> {
     }
 }
 
-impl<'l, 'r> Add<LBNumRef<'r>> for &'l LBNum {
+impl<'r> Add<LBNumRef<'r>> for &LBNum {
     type Output = LBNum;
     #[inline(always)]
     fn add(self, rhs: LBNumRef<'r>) -> LBNum {
         Add::add(self.clone(), rhs)
     }
 }
-impl<'l, 'r> Add<&'r LBNum> for &'l LBNum {
+impl<'r> Add<&'r LBNum> for &LBNum {
     type Output = LBNum;
     #[inline(always)]
     fn add(self, rhs: &'r LBNum) -> LBNum {
         Add::add(self.clone(), rhs.borrow())
     }
 }
-impl<'l> Add<LBNum> for &'l LBNum {
+impl Add<LBNum> for &LBNum {
     type Output = LBNum;
     #[inline(always)]
     fn add(self, rhs: LBNum) -> LBNum {
@@ -105,7 +109,7 @@ impl<'r> Add<LBNumRef<'r>> for LBNum {
 impl<'r> Add<&'r Self> for LBNum {
     type Output = LBNum;
     #[inline(always)]
-    fn add(mut self, rhs: &'r Self) -> LBNum {
+    fn add(self, rhs: &'r Self) -> LBNum {
         Add::add(self, rhs.borrow())
     }
 }
@@ -120,26 +124,7 @@ impl Add<Self> for LBNum {
 impl AddAssign<LBNumRef<'_>> for LBNum {
     #[inline]
     fn add_assign(&mut self, rhs: LBNumRef<'_>) {
-        if rhs.is_empty() {return;}
-        if self.0.len() < rhs.len() {
-            self.0.inner_mut().resize(rhs.len(), LinkedByte::ZERO_LINK);
-        }
-        // Create a pair iterator. For every value of this, other is its corresponding value from rhs.
-        for (i, other) in (0..self.0.len()).zip(rhs.iter_le()) {
-            let this = &mut self.0.inner_mut()[i];
-            let (val, wrapped) = this.add_with_carry(other);
-            *this = val;
-            if wrapped {
-                if i == self.0.len() - 1 {
-                    // If we're right at the end, just push a new element.
-                    self.0.inner_mut().push(LinkedByte::from(1));
-                } else {
-                    // If not, increment the next byte.
-                    self.increment_at_index(i + 1);
-                }
-            }
-        }
-        Self::fix_in_place(self.0.inner_mut());
+        self.add_digits_at(0, rhs.iter_le().map(LinkedByte::value));
     }
 }
 impl AddAssign<&LBNum> for LBNum {
@@ -170,26 +155,9 @@ macro_rules! impl_add_with_primitive {
         }
 
         impl AddAssignAt<$ty> for LBNum {
+            #[inline(always)]
             fn add_assign_at(&mut self, byte: usize, rhs: $ty) {
-                if (self.0.inner().len() - 1) < byte {
-                    self.0.inner_mut().resize(byte + 2, LinkedByte::ZERO_END);
-                }
-                let div_by = LinkedByte::MAX as u128 + 1;
-                let (rep, rem) = (rhs as u128 / div_by, rhs as u128 % div_by);
-                for _ in 0..rep {
-                    let (val, wrapped) = self.0.inner_mut()[byte + 1].add_with_carry(LinkedByte::from(1));
-                    if wrapped {self.increment_at_index(byte + 2);}
-                    // The check at the beginning of the function lets us be sure that there is always a byte at that index.
-                    *self.0.get_mut(byte + 1).unwrap_or_else(||{unsafe {hint::unreachable_unchecked()}}) = val;
-                }
-                let rem: u8 = rem.try_into().unwrap();
-                let (val, wrapped) = self.0.inner_mut()[byte].add_with_carry(LinkedByte::from(rem));
-                if wrapped {self.increment_at_index(byte + 1);}
-                // Same justification as above.
-                *self.0.get_mut(byte).unwrap_or_else(||{unsafe {hint::unreachable_unchecked()}}) = val;
-
-                self.zero_fold();
-                Self::fix_in_place(&mut self.0.inner_mut()[..]);
+                self.add_digits_at(byte, u128_digits(rhs as u128));
             }
         }
 
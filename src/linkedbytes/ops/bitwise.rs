@@ -0,0 +1,305 @@
+#![cfg_attr(feature = "clippy", allow(clippy::use_self))]
+
+use crate::linkedbytes::{LBNum, LBNumRef, LinkedByte};
+use super::u128_digits;
+use core::{
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Shl, ShlAssign, Shr, ShrAssign},
+    convert::TryInto,
+    iter,
+};
+
+/// The number of bits stored in every linked byte.
+const DIGIT_BITS: usize = 7;
+
+impl LBNum {
+    /// Applies a bitwise operation to every pair of base-128 digits from `self` and `rhs`, with the missing digits of the shorter operand treated as zeros.
+    ///
+    /// Since every digit holds exactly 7 bits of the number, the `&`, `|` and `^` operators, both with other Linked Bytes numbers and with primitive integers, are implemented on top of this.
+    pub(crate) fn bitwise_digits(&mut self, rhs: impl IntoIterator<Item = u8>, op: impl Fn(u8, u8) -> u8) {
+        let inner = self.0.inner_mut();
+        let mut rhs = rhs.into_iter().fuse();
+        let mut i = 0;
+        loop {
+            let digit = rhs.next();
+            if digit.is_none() && i >= inner.len() {break;}
+            if inner.len() <= i {
+                inner.push(LinkedByte::ZERO_LINK);
+            }
+            inner[i] = LinkedByte::from((op(inner[i].value(), digit.unwrap_or(0)), true));
+            i += 1;
+        }
+        self.zero_fold();
+    }
+
+    /// Returns the number of ones in the binary representation of the number.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.iter_le().map(|byte| byte.value().count_ones() as usize).sum()
+    }
+    /// Returns the number of bits required to represent the number, i.e. the index of the highest set bit plus one, or 0 if the number is zero.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        match self.iter_be().next() {
+            // The most significant digit is never zero.
+            Some(top) => (self.num_bytes() - 1) * DIGIT_BITS + (8 - top.value().leading_zeros() as usize),
+            None => 0,
+        }
+    }
+    /// Returns the number of zeros at the least significant end of the binary representation of the number, or `None` if the number is zero, since it has infinitely many of them.
+    #[inline]
+    pub fn trailing_zeros(&self) -> Option<usize> {
+        let (index, digit) = self.iter_le()
+            .map(LinkedByte::value)
+            .enumerate()
+            .find(|&(_, digit)| digit != 0)?;
+        Some(index * DIGIT_BITS + digit.trailing_zeros() as usize)
+    }
+    /// Returns the value of the bit at the specified index, with 0 being the least significant bit.
+    ///
+    /// Bits beyond the most significant digit are zero.
+    #[inline]
+    pub fn bit(&self, index: usize) -> bool {
+        self.0.get(index / DIGIT_BITS)
+            .is_some_and(|byte| byte.value() & (1 << (index % DIGIT_BITS)) != 0)
+    }
+    /// Sets the bit at the specified index, with 0 being the least significant bit, to the specified value.
+    ///
+    /// Setting a bit beyond the most significant digit extends the number as required.
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        let (digit, mask) = (index / DIGIT_BITS, 1_u8 << (index % DIGIT_BITS));
+        let inner = self.0.inner_mut();
+        if digit >= inner.len() {
+            if !value {return;}
+            inner.resize(digit + 1, LinkedByte::ZERO_LINK);
+        }
+        let new_value = match value {
+            true => inner[digit].value() | mask,
+            false => inner[digit].value() & !mask,
+        };
+        inner[digit] = LinkedByte::from((new_value, true));
+        self.zero_fold();
+    }
+
+    /// Shifts the number to the left by the specified amount of bits in place, i.e. multiplies it by 2 raised to that power.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // Bit counts below 7 and the low 7 bits of shifted digits are what's cast.
+    fn shl_bits(&mut self, bits: usize) {
+        if self.num_bytes() == 0 {return;}
+        let (digits, bits) = (bits / DIGIT_BITS, (bits % DIGIT_BITS) as u32);
+        let inner = self.0.inner_mut();
+        if bits != 0 {
+            let mut carry = 0_u8;
+            for byte in inner.iter_mut() {
+                let shifted = (byte.value() as u16) << bits;
+                *byte = LinkedByte::from(((shifted as u8 & LinkedByte::VALUE_MASK) | carry, true));
+                carry = (shifted >> DIGIT_BITS) as u8;
+            }
+            if carry != 0 {inner.push(LinkedByte::from(carry));}
+        }
+        // Whole digits are shifted by prepending zeros, just like AddAt does.
+        inner.splice(0..0, iter::repeat(LinkedByte::ZERO_LINK).take(digits));
+        self.zero_fold();
+    }
+    /// Shifts the number to the right by the specified amount of bits in place, i.e. divides it by 2 raised to that power, rounding down.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // Bit counts below 7 and the low 7 bits of shifted digits are what's cast.
+    fn shr_bits(&mut self, bits: usize) {
+        let (digits, bits) = (bits / DIGIT_BITS, (bits % DIGIT_BITS) as u32);
+        let inner = self.0.inner_mut();
+        if digits >= inner.len() {
+            inner.clear();
+            return;
+        }
+        inner.drain(..digits);
+        if bits != 0 {
+            let mut carry = 0_u8;
+            for byte in inner.iter_mut().rev() {
+                let value = byte.value();
+                *byte = LinkedByte::from(((value >> bits) | carry, true));
+                carry = (value << (DIGIT_BITS as u32 - bits)) & LinkedByte::VALUE_MASK;
+            }
+        }
+        self.zero_fold();
+    }
+}
+
+// Implementation checklist, for each of &, | and ^:
+// | lhs  |  rhs | LBNumRef  | reference | value | coreint   |
+// | LBNumRef    | yes ~     | yes ~     | yes ~ | no        |
+// | reference   | yes ~     | yes ~     | yes ~ | no        |
+// | value       | yes ~     | yes ~     | yes ~ | yes ~     |
+// | coreint     | no        | no        | yes ~ | N/A       |
+// | value op=   | yes ~     | yes ~     | yes ~ | yes ~     |
+// Shifts are implemented for all of LBNumRef, reference and value on the left and coreint on the right.
+
+macro_rules! impl_bitop {
+    ($tr:ident $method:ident $tr_assign:ident $method_assign:ident $op:tt) => {
+        impl<'l, 'r> $tr<LBNumRef<'r>> for LBNumRef<'l> {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: LBNumRef<'r>) -> LBNum {
+                $tr::$method(self.into_owned(), rhs)
+            }
+        }
+        impl<'l, 'r> $tr<&'r LBNum> for LBNumRef<'l> {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: &'r LBNum) -> LBNum {
+                $tr::$method(self.into_owned(), rhs.borrow())
+            }
+        }
+        impl<'l> $tr<LBNum> for LBNumRef<'l> {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: LBNum) -> LBNum {
+                $tr::$method(rhs, self)
+            }
+        }
+
+        impl<'l, 'r> $tr<LBNumRef<'r>> for &'l LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: LBNumRef<'r>) -> LBNum {
+                $tr::$method(self.clone(), rhs)
+            }
+        }
+        impl<'l, 'r> $tr<&'r LBNum> for &'l LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: &'r LBNum) -> LBNum {
+                $tr::$method(self.clone(), rhs.borrow())
+            }
+        }
+        impl<'l> $tr<LBNum> for &'l LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: LBNum) -> LBNum {
+                $tr::$method(rhs, self)
+            }
+        }
+
+        impl<'r> $tr<LBNumRef<'r>> for LBNum {
+            type Output = LBNum;
+            #[inline]
+            fn $method(mut self, rhs: LBNumRef<'r>) -> LBNum {
+                $tr_assign::$method_assign(&mut self, rhs);
+                self
+            }
+        }
+        impl<'r> $tr<&'r Self> for LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: &'r Self) -> LBNum {
+                $tr::$method(self, rhs.borrow())
+            }
+        }
+        impl $tr<Self> for LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> LBNum {
+                $tr::$method(self, rhs.borrow())
+            }
+        }
+
+        impl $tr_assign<LBNumRef<'_>> for LBNum {
+            #[inline]
+            fn $method_assign(&mut self, rhs: LBNumRef<'_>) {
+                self.bitwise_digits(rhs.iter_le().map(LinkedByte::value), |lhs, rhs| lhs $op rhs);
+            }
+        }
+        impl $tr_assign<&LBNum> for LBNum {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: &LBNum) {
+                $tr_assign::$method_assign(self, rhs.borrow())
+            }
+        }
+        impl $tr_assign<LBNum> for LBNum {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: LBNum) {
+                $tr_assign::$method_assign(self, rhs.borrow())
+            }
+        }
+
+        impl_bitop_with_primitive!($tr $method $tr_assign $method_assign $op; u8 u16 u32 u64 u128 usize);
+    };
+}
+macro_rules! impl_bitop_with_primitive {
+    ($tr:ident $method:ident $tr_assign:ident $method_assign:ident $op:tt; $($ty:ident)+) => ($(
+        impl $tr<$ty> for LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn $method(mut self, rhs: $ty) -> LBNum {
+                $tr_assign::$method_assign(&mut self, rhs);
+                self
+            }
+        }
+        impl $tr<LBNum> for $ty {
+            type Output = LBNum;
+            #[inline(always)]
+            // All bitwise operations are commutative.
+            fn $method(self, rhs: LBNum) -> LBNum {
+                $tr::$method(rhs, self)
+            }
+        }
+        impl $tr_assign<$ty> for LBNum {
+            #[inline(always)]
+            fn $method_assign(&mut self, rhs: $ty) {
+                self.bitwise_digits(u128_digits(rhs as u128), |lhs, rhs| lhs $op rhs);
+            }
+        }
+    )+)
+}
+
+impl_bitop!(BitAnd bitand BitAndAssign bitand_assign &);
+impl_bitop!(BitOr bitor BitOrAssign bitor_assign |);
+impl_bitop!(BitXor bitxor BitXorAssign bitxor_assign ^);
+
+macro_rules! impl_shifts {
+    ($($ty:ident)+) => ($(
+        impl ShlAssign<$ty> for LBNum {
+            #[inline(always)]
+            fn shl_assign(&mut self, rhs: $ty) {
+                self.shl_bits(rhs.try_into().expect("shift amount does not fit into usize"));
+            }
+        }
+        impl Shl<$ty> for LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shl(mut self, rhs: $ty) -> LBNum {self <<= rhs; self}
+        }
+        impl Shl<$ty> for &LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shl(self, rhs: $ty) -> LBNum {self.clone() << rhs}
+        }
+        impl Shl<$ty> for LBNumRef<'_> {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shl(self, rhs: $ty) -> LBNum {self.into_owned() << rhs}
+        }
+
+        impl ShrAssign<$ty> for LBNum {
+            #[inline(always)]
+            fn shr_assign(&mut self, rhs: $ty) {
+                // Shifting by more bits than there are in usize always gives zero.
+                self.shr_bits(rhs.try_into().unwrap_or(usize::MAX));
+            }
+        }
+        impl Shr<$ty> for LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shr(mut self, rhs: $ty) -> LBNum {self >>= rhs; self}
+        }
+        impl Shr<$ty> for &LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shr(self, rhs: $ty) -> LBNum {self.clone() >> rhs}
+        }
+        impl Shr<$ty> for LBNumRef<'_> {
+            type Output = LBNum;
+            #[inline(always)]
+            fn shr(self, rhs: $ty) -> LBNum {self.into_owned() >> rhs}
+        }
+    )+)
+}
+impl_shifts! {
+    u8 u16 u32 u64 u128 usize
+}
@@ -1,13 +1,111 @@
 #![cfg_attr(feature = "clippy", allow(clippy::use_self))]
 
 use crate::{
-    linkedbytes::{LBNum, LBNumRef},
+    linkedbytes::{LBNum, LBNumRef, LinkedByte},
     DivRem, DivRemAssign,
 };
-use core::{
-    ops::{Div, DivAssign, Rem, RemAssign},
-    mem,
-};
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+use alloc::{vec, vec::Vec};
+
+/// The base of the digits used by Linked Bytes numbers.
+const BASE: u32 = LinkedByte::MAX as u32 + 1;
+
+/// Divides a number by a single base-128 digit, returning the quotient and the remainder. The digits are in little-endian order.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // Quotient digits and the remainder are always below the divisor, which is a single digit.
+pub(super) fn short_division(lhs: &[u8], rhs: u8) -> (Vec<u8>, u8) {
+    let rhs = rhs as u32;
+    let mut quotient = vec![0_u8; lhs.len()];
+    let mut remainder = 0_u32;
+    for (i, &digit) in lhs.iter().enumerate().rev() {
+        let current = remainder * BASE + digit as u32;
+        quotient[i] = (current / rhs) as u8;
+        remainder = current % rhs;
+    }
+    (quotient, remainder as u8)
+}
+/// Shifts the base-128 digits to the left by the specified amount of bits (less than 7), returning the shifted digits with one extra digit at the most significant end for the bits which were shifted out.
+fn shift_digits_left(op: &[u8], shift: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(op.len() + 1);
+    let mut carry = 0_u32;
+    for &digit in op {
+        let shifted = ((digit as u32) << shift) | carry;
+        result.push(shifted % BASE);
+        carry = shifted / BASE;
+    }
+    result.push(carry);
+    result
+}
+/// Divides a number by another one using schoolbook long division ([Knuth's Algorithm D][0]), returning the quotient and the remainder. The digits are in little-endian order, and the most significant digit of the divisor must not be zero.
+///
+/// [0]: https://skanthak.homepage.t-online.de/division.html "Donald Knuth's Algorithm D, as described in The Art of Computer Programming, Volume 2, section 4.3.1"
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // Digits are below 128, and the quotient estimate is corrected to a single digit before it's stored.
+fn long_division(lhs: &[u8], rhs: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let n = rhs.len();
+    if lhs.len() < n {
+        return (Vec::new(), lhs.to_vec());
+    }
+    if n == 1 {
+        let (quotient, remainder) = short_division(lhs, rhs[0]);
+        return (quotient, vec![remainder]);
+    }
+    let m = lhs.len() - n;
+    // Normalize the operands so that the most significant digit of the divisor has its highest bit set, which makes the quotient digit estimates off by at most 2.
+    let shift = (LinkedByte::VALUE_MASK as u32).count_ones() - (32 - (rhs[n - 1] as u32).leading_zeros());
+    let divisor = shift_digits_left(rhs, shift);
+    let mut dividend = shift_digits_left(lhs, shift).into_iter().map(|digit| digit as i64).collect::<Vec<_>>();
+    let (top, second) = (divisor[n - 1], divisor[n - 2]);
+    let mut quotient = vec![0_u8; m + 1];
+    for j in (0..=m).rev() {
+        let numerator = dividend[j + n] as u32 * BASE + dividend[j + n - 1] as u32;
+        let (mut qhat, mut rhat) = (numerator / top, numerator % top);
+        while qhat >= BASE || qhat * second > BASE * rhat + dividend[j + n - 2] as u32 {
+            qhat -= 1;
+            rhat += top;
+            if rhat >= BASE {break;}
+        }
+        // Multiply the divisor by the estimated digit and subtract the result from the current part of the dividend.
+        let mut borrow = 0_i64;
+        for i in 0..n {
+            let product = qhat as i64 * divisor[i] as i64;
+            let difference = dividend[i + j] - borrow - (product & (BASE as i64 - 1));
+            dividend[i + j] = difference & (BASE as i64 - 1);
+            borrow = (product >> 7) - (difference >> 7);
+        }
+        let difference = dividend[j + n] - borrow;
+        dividend[j + n] = difference;
+        if difference < 0 {
+            // The estimate was one too large, so add the divisor back.
+            qhat -= 1;
+            let mut carry = 0_i64;
+            for i in 0..n {
+                let sum = dividend[i + j] + divisor[i] as i64 + carry;
+                dividend[i + j] = sum & (BASE as i64 - 1);
+                carry = sum >> 7;
+            }
+            dividend[j + n] += carry;
+        }
+        quotient[j] = qhat as u8;
+    }
+    // Undo the normalization for the remainder.
+    let mut remainder = vec![0_u8; n];
+    for i in 0..n {
+        let combined = (dividend[i] as u32 >> shift) | ((dividend[i + 1] as u32) << (7 - shift));
+        remainder[i] = (combined % BASE) as u8;
+    }
+    (quotient, remainder)
+}
+
+impl LBNum {
+    /// Performs in-place division by a sequence of base-128 digits, given in little-endian order, returning the remainder. Division and remainder by both Linked Bytes numbers and primitive integers go through this.
+    fn div_rem_assign_digits(&mut self, rhs: &[u8]) -> Self {
+        let significant = rhs.iter().rposition(|&digit| digit != 0).expect("attempt to divide a Linked Bytes number by zero");
+        let rhs = &rhs[..=significant];
+        let lhs = self.digits().collect::<Vec<_>>();
+        let (quotient, remainder) = long_division(&lhs, rhs);
+        *self = Self::from_digits(quotient);
+        Self::from_digits(remainder)
+    }
+}
 
 impl DivRem<&Self> for LBNum {
     type Quotient = Self; type Remainder = Self;
@@ -43,16 +141,7 @@ impl DivRemAssign<&Self> for LBNum {
     /// Dividing by 0 triggers an immediate panic.
     #[inline]
     fn div_rem_assign(&mut self, rhs: &Self) -> Self {
-        assert!(rhs > &0_u8);
-        let mut quotient = Self::ZERO;
-        loop {
-            if (self as &Self) < rhs {break;}
-            unsafe {self.checked_sub_assign(rhs.into());}
-            quotient.increment();
-        }
-        core::mem::replace(self, quotient) // This moves the remainder out of self, moves the quotient and tail-returns it to the callee.
-                                           // While this kind of call might indeed be unintuitive, reading the core::mem::replace docs is
-                                           // all you need to do to understand this just fine.
+        self.div_rem_assign(rhs.borrow())
     }
 }
 impl DivRemAssign<Self> for LBNum {
@@ -75,16 +164,8 @@ impl DivRemAssign<LBNumRef<'_>> for LBNum {
     /// # Panics
     /// Dividing by 0 triggers an immediate panic.
     fn div_rem_assign(&mut self, rhs: LBNumRef<'_>) -> Self {
-        assert!(rhs > 0_u8);
-        let mut quotient = Self::ZERO;
-        loop {
-            if *self < rhs {break;}
-            unsafe {self.checked_sub_assign(rhs);}
-            quotient.increment();
-        }
-        core::mem::replace(self, quotient) // This moves the remainder out of self, moves the quotient and tail-returns it to the callee.
-                                           // While this kind of call might indeed be unintuitive, reading the core::mem::replace docs is
-                                           // all you need to do to understand this just fine.
+        let rhs = rhs.iter_le().map(LinkedByte::value).collect::<Vec<_>>();
+        self.div_rem_assign_digits(&rhs)
     }
 }
 impl Div<&Self> for LBNum {
@@ -199,14 +280,8 @@ macro_rules! impl_div_by_primitive {
 
             #[inline]
             fn div_rem_assign(&mut self, rhs: $ty) -> Self {
-                assert!(rhs > 0);
-                let mut quotient = Self::ZERO;
-                loop {
-                    if (self as &Self) < &rhs {break;}
-                    unsafe {self.checked_sub_assign(LBNum::from(rhs).borrow());}
-                    quotient.increment();
-                }
-                mem::replace(self, quotient)
+                let rhs = super::u128_digits(rhs as u128).collect::<Vec<_>>();
+                self.div_rem_assign_digits(&rhs)
             }
         }
 
@@ -221,16 +296,9 @@ macro_rules! impl_div_by_primitive {
             }
         }
         impl DivAssign<$ty> for LBNum {
-            #[inline]
+            #[inline(always)]
             fn div_assign(&mut self, rhs: $ty) {
-                assert!(rhs > 0);
-                let mut result = Self::ZERO;
-                loop {
-                    if (self as &Self) < &rhs {break;}
-                    unsafe {self.checked_sub_assign(LBNum::from(rhs).borrow());}
-                    result.increment();
-                }
-                *self = result;
+                self.div_rem_assign(rhs);
             }
         }
 
@@ -20,7 +20,7 @@ impl LBNum {
     /// # Panics
     /// Passing a radix less than 2 or greater than 36 results an immediate panic, even if the value is 0.
     pub fn into_string_with_radix(mut self, radix: u8) -> String {
-        if radix < 2 || radix > 36 { // Make sure that the radix is valid.
+        if !(2..=36).contains(&radix) { // Make sure that the radix is valid.
             panic!("invalid value for radix (not in range from 2 to 36, inclusively)");
         }
         if self == 0_u8 {return '0'.to_string();} // Avoid an empty string condition.
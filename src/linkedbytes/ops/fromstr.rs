@@ -0,0 +1,157 @@
+use crate::LBNum;
+use core::{
+    fmt::{self, Formatter, Display},
+    str::FromStr,
+};
+
+impl LBNum {
+    /// Parses a Linked Bytes number from a string with an arbitrary radix (base), from 2 to 36 inclusively.
+    ///
+    /// Both uppercase and lowercase letters are accepted as digits above 9. Underscores (`_`) are allowed anywhere after the first digit and are ignored, which allows separating groups of digits. A leading `+` is allowed. If the radix is 2, 8 or 16, the string may start with the respective Rust-style prefix (`0b`, `0o` or `0x`, in any case) — other prefixes aren't recognized, since they'd be ambiguous with digits in higher radices.
+    ///
+    /// # Errors
+    /// Returns [`ParseLBNumError`][0] if the string has no digits or contains a character which isn't a valid digit in the specified radix. The error reports the byte position of the offending character.
+    ///
+    /// # Panics
+    /// Passing a radix less than 2 or greater than 36 results an immediate panic, even if the string is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::LBNum;
+    /// assert_eq!(LBNum::from_str_radix("0xDEAD_BEEF", 16).unwrap(), 0xDEAD_BEEF_u32);
+    /// assert_eq!(LBNum::from_str_radix("zz", 36).unwrap(), 36_u16 * 36 - 1);
+    /// let error = LBNum::from_str_radix("12_3a", 10).unwrap_err();
+    /// assert_eq!(error.position(), 4);
+    /// ```
+    ///
+    /// [0]: struct.ParseLBNumError.html "ParseLBNumError — the error type for parsing Linked Bytes numbers from strings"
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseLBNumError> {
+        if !(2..=36).contains(&radix) { // Make sure that the radix is valid.
+            panic!("invalid value for radix (not in range from 2 to 36, inclusively)");
+        }
+        let start = Self::sign_len(src);
+        let prefix = match radix {
+            2 => Some('b'),
+            8 => Some('o'),
+            16 => Some('x'),
+            _ => None,
+        };
+        let start = start + prefix.map_or(0, |prefix| Self::prefix_len(&src[start..], prefix));
+        Self::parse_digits(src, start, radix)
+    }
+    /// Returns the length of the `+` sign at the start of the string, if there is one.
+    #[inline(always)]
+    fn sign_len(src: &str) -> usize {
+        usize::from(src.starts_with('+'))
+    }
+    /// Returns the length of the specified radix prefix at the start of the string, if it's there.
+    fn prefix_len(src: &str, prefix: char) -> usize {
+        let mut chars = src.chars();
+        match (chars.next(), chars.next()) {
+            (Some('0'), Some(c)) if c.to_ascii_lowercase() == prefix => 2,
+            _ => 0,
+        }
+    }
+    /// Parses the digits in `src[start..]`, reporting error positions relative to the whole string.
+    fn parse_digits(src: &str, start: usize, radix: u32) -> Result<Self, ParseLBNumError> {
+        // Digits are gathered into a machine word first, so that the number is only multiplied once per several digits.
+        let mut result = Self::ZERO;
+        let (mut chunk, mut chunk_multiplier) = (0_u64, 1_u64);
+        let mut any_digits = false;
+        for (position, c) in src[start..].char_indices() {
+            let position = start + position;
+            if c == '_' && any_digits {continue;}
+            let digit = c.to_digit(radix)
+                .ok_or(ParseLBNumError {kind: ParseLBNumErrorKind::InvalidDigit(c), position})?;
+            any_digits = true;
+            if let Some(multiplier) = chunk_multiplier.checked_mul(radix as u64) {
+                chunk = chunk * radix as u64 + digit as u64;
+                chunk_multiplier = multiplier;
+            } else {
+                result *= chunk_multiplier;
+                result += chunk;
+                chunk = digit as u64;
+                chunk_multiplier = radix as u64;
+            }
+        }
+        if !any_digits {
+            return Err(ParseLBNumError {kind: ParseLBNumErrorKind::NoDigits, position: src.len()});
+        }
+        result *= chunk_multiplier;
+        result += chunk;
+        Ok(result)
+    }
+}
+impl FromStr for LBNum {
+    type Err = ParseLBNumError;
+    /// Parses a Linked Bytes number from a string, in decimal unless the string starts with a `0x`, `0o` or `0b` prefix, which selects hexadecimal, octal or binary respectively.
+    ///
+    /// See [`from_str_radix`][0] for the details on the syntax.
+    ///
+    /// # Example
+    /// ```
+    /// # use bigbit::LBNum;
+    /// let number: LBNum = "1_000_000".parse().unwrap();
+    /// assert_eq!(number, 1_000_000_u32);
+    /// let number: LBNum = "0b1010".parse().unwrap();
+    /// assert_eq!(number, 10_u8);
+    /// ```
+    ///
+    /// [0]: #method.from_str_radix "from_str_radix — parses a Linked Bytes number from a string with an arbitrary radix (base)"
+    fn from_str(src: &str) -> Result<Self, ParseLBNumError> {
+        let start = Self::sign_len(src);
+        for &(prefix, radix) in &[('x', 16), ('o', 8), ('b', 2)] {
+            let prefix_len = Self::prefix_len(&src[start..], prefix);
+            if prefix_len != 0 {
+                return Self::parse_digits(src, start + prefix_len, radix);
+            }
+        }
+        Self::parse_digits(src, start, 10)
+    }
+}
+
+/// The error type for parsing Linked Bytes numbers from strings.
+///
+/// Along with the [kind of the error][0], the byte position in the string at which it was encountered is provided.
+///
+/// [0]: enum.ParseLBNumErrorKind.html "ParseLBNumErrorKind — the kind of error encountered while parsing a Linked Bytes number"
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParseLBNumError {
+    pub(crate) kind: ParseLBNumErrorKind,
+    pub(crate) position: usize,
+}
+impl ParseLBNumError {
+    /// Returns the kind of the error.
+    #[inline(always)]
+    pub const fn kind(self) -> ParseLBNumErrorKind {
+        self.kind
+    }
+    /// Returns the byte position in the string at which the error was encountered.
+    ///
+    /// For [`NoDigits`][0], this is the length of the string.
+    ///
+    /// [0]: enum.ParseLBNumErrorKind.html#variant.NoDigits "ParseLBNumErrorKind::NoDigits — the string didn't contain any digits"
+    #[inline(always)]
+    pub const fn position(self) -> usize {
+        self.position
+    }
+}
+impl Display for ParseLBNumError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            ParseLBNumErrorKind::NoDigits => f.write_str("cannot parse Linked Bytes number from string without digits"),
+            ParseLBNumErrorKind::InvalidDigit(c) => write!(f, "invalid digit {:?} found in string at position {}", c, self.position),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLBNumError {}
+
+/// The kind of error encountered while parsing a Linked Bytes number.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ParseLBNumErrorKind {
+    /// The string didn't contain any digits, i.e. it was empty or only consisted of a sign, a prefix and/or separators.
+    NoDigits,
+    /// The string contained a character which isn't a valid digit in the radix being used. The character is included.
+    InvalidDigit(char),
+}
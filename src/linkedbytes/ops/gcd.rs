@@ -1,11 +1,14 @@
 use crate::{
-    Gcd,
+    Gcd, ExtendedGcd, DivRem, Sign,
     linkedbytes::*,
 };
+use core::mem;
 
 impl Gcd for LBNum {
     /// Uses the Euclidean algorithm to calculate the GCD of two numbers encoded using Linked Bytes.
     ///
+    /// Each step replaces the larger number by the remainder of its division by the smaller one, so the amount of steps is proportional to the number of digits rather than the difference between the operands.
+    ///
     /// # Usage
     /// ```
     /// # use bigbit::prelude::*;
@@ -17,24 +20,64 @@ impl Gcd for LBNum {
     /// # assert_eq!(gcd, 6_u8);
     /// ```
     fn gcd(mut lhs: Self, mut rhs: Self) -> Self {
-        loop {
-            match lhs.cmp(&rhs) {
-                Ordering::Greater => {
-                    // lhs is greater than rhs
-                    dbg!(&lhs);
-                    dbg!(&rhs);
-                    lhs -= &rhs;
-                },
-                Ordering::Less => {
-                    // rhs is greater than lhs
-                    dbg!(&lhs);
-                    dbg!(&rhs);
-                    rhs -= &lhs;
-                },
-                Ordering::Equal => break,
-            };
+        while rhs.num_bytes() != 0 {
+            let remainder = lhs % &rhs;
+            lhs = mem::replace(&mut rhs, remainder);
         }
-        // It obviously doesn't matter which one we return, since we only get here when both are equal.
         lhs
     }
-}
\ No newline at end of file
+    /// Calculates the LCM of two numbers encoded using Linked Bytes by dividing one of them by their GCD and multiplying the result by the other one.
+    ///
+    /// # Usage
+    /// ```
+    /// # use bigbit::prelude::*;
+    /// let lcm = bigbit::lcm(LBNum::from(18_u8), LBNum::from(12_u8));
+    /// assert_eq!(lcm, 36_u8);
+    /// ```
+    fn lcm(lhs: Self, rhs: Self) -> Self {
+        if lhs.num_bytes() == 0 || rhs.num_bytes() == 0 {
+            return Self::ZERO;
+        }
+        let gcd = Self::gcd(lhs.clone(), rhs.clone());
+        (lhs / gcd) * rhs
+    }
+}
+impl ExtendedGcd for LBNum {
+    /// The coefficients are stored as their sign and absolute value, since `LBNum` itself is unsigned.
+    type Coefficient = (Sign, Self);
+    /// Uses the extended Euclidean algorithm to calculate the GCD and the Bézout coefficients of two numbers encoded using Linked Bytes.
+    ///
+    /// The coefficients are the smallest ones produced by the algorithm: unless one of the operands divides the other, the absolute value of the coefficient for `lhs` is less than `rhs / gcd` and vice versa.
+    ///
+    /// # Usage
+    /// ```
+    /// # use bigbit::{prelude::*, Sign};
+    /// let (gcd, x, y) = bigbit::extended_gcd(LBNum::from(240_u8), LBNum::from(46_u8));
+    /// assert_eq!(gcd, 2_u8);
+    /// // 240 · (-9) + 46 · 47 = 2
+    /// assert_eq!(x, (Sign::Negative, LBNum::from(9_u8)));
+    /// assert_eq!(y, (Sign::Positive, LBNum::from(47_u8)));
+    /// ```
+    fn extended_gcd(lhs: Self, rhs: Self) -> (Self, Self::Coefficient, Self::Coefficient) {
+        // The signs of the coefficients alternate with every step, so only their absolute values need to be tracked.
+        let (mut old_r, mut r) = (lhs, rhs);
+        let (mut old_s, mut s) = (Self::from(1_u8), Self::ZERO);
+        let (mut old_t, mut t) = (Self::ZERO, Self::from(1_u8));
+        let mut odd_steps = false;
+        while r.num_bytes() != 0 {
+            let (quotient, remainder) = old_r.div_rem(&r);
+            old_r = mem::replace(&mut r, remainder);
+            let new_s = old_s + &quotient * &s;
+            old_s = mem::replace(&mut s, new_s);
+            let new_t = old_t + &quotient * &t;
+            old_t = mem::replace(&mut t, new_t);
+            odd_steps = !odd_steps;
+        }
+        let sign_of = |magnitude: &Self, negative: bool| {
+            Sign::from(negative && magnitude.num_bytes() != 0)
+        };
+        let s_sign = sign_of(&old_s, odd_steps);
+        let t_sign = sign_of(&old_t, !odd_steps);
+        (old_r, (s_sign, old_s), (t_sign, old_t))
+    }
+}
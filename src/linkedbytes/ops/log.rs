@@ -0,0 +1,61 @@
+use crate::linkedbytes::LBNum;
+
+impl LBNum {
+    /// Returns the base 2 logarithm of the number, rounded down, or `None` if the number is zero.
+    #[inline]
+    pub fn ilog2(&self) -> Option<usize> {
+        self.bit_length().checked_sub(1)
+    }
+    /// Returns the base 10 logarithm of the number, rounded down, or `None` if the number is zero.
+    #[inline]
+    pub fn ilog10(&self) -> Option<usize> {
+        self.checked_ilog(&Self::from(10_u8))
+    }
+    /// Returns the logarithm of the number with respect to an arbitrary base, rounded down, or `None` if the number is zero or the base is less than 2.
+    ///
+    /// Instead of dividing the number by the base until it's exhausted, the result is estimated using floating-point arithmetic and then corrected, which only takes a few multiplications.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // The estimate is a small non-negative float which gets corrected afterwards.
+    pub fn checked_ilog(&self, base: &Self) -> Option<usize> {
+        if *self == 0_u8 || *base < 2_u8 {return None;}
+        if *base == 2_u8 {return self.ilog2();}
+        if base > self {return Some(0);}
+        // The estimate is only off by one at most, unless the numbers have trillions of bits. Starting below the result keeps the correction to multiplications only.
+        let estimate = (log2(self) / log2(base)) as usize;
+        let mut result = estimate.saturating_sub(1);
+        let mut power = base.pow_lb(&Self::from(result));
+        while power > *self {
+            power /= base;
+            result -= 1;
+        }
+        loop {
+            let next = &power * base;
+            if next > *self {break;}
+            power = next;
+            result += 1;
+        }
+        Some(result)
+    }
+}
+
+/// Approximates the base 2 logarithm of a nonzero number, using fixed-point arithmetic, since `f64::log2` is not available without `std`.
+fn log2(op: &LBNum) -> f64 {
+    const FRACTION_BITS: u32 = 32;
+    let (top, exp2) = op.top_bits();
+    let top_bit = top.ilog2();
+    // The mantissa is in the range [1; 2), with the integral part being the 33rd bit.
+    let mut mantissa = match top_bit.checked_sub(FRACTION_BITS) {
+        Some(shift) => top >> shift,
+        None => top << (FRACTION_BITS - top_bit),
+    };
+    // Squaring the mantissa doubles its logarithm, which shifts the next bit of the fractional part into the integral part.
+    let mut fraction = 0_u64;
+    for _ in 0..FRACTION_BITS {
+        mantissa = (mantissa * mantissa) >> FRACTION_BITS;
+        fraction <<= 1;
+        if mantissa >= 2 << FRACTION_BITS {
+            mantissa >>= 1;
+            fraction |= 1;
+        }
+    }
+    (top_bit as i64 + exp2) as f64 + fraction as f64 / (1_u64 << FRACTION_BITS) as f64
+}
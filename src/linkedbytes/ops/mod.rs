@@ -1,10 +1,28 @@
 use crate::linkedbytes::{LBNum, LBNumRef};
-use core::cmp::{
-    self, PartialOrd, Ord, Ordering,
+use core::{
+    cmp::{PartialOrd, Ord, Ordering},
+    convert::TryFrom,
+    iter,
 };
 
-mod add; mod sub; mod mul; mod div; mod from; mod tryinto; mod fmt; mod gcd;
-pub(crate) use sub::DecrementResult;
+mod add; mod sub; mod mul; mod div; mod pow; mod log; mod root; mod bitwise; mod from; mod tryinto; mod fmt; mod gcd; mod fromstr;
+#[cfg(feature = "num_traits")]
+mod num;
+#[cfg(feature = "serde")]
+mod serde;
+pub use tryinto::TryFromIntError;
+pub use fromstr::{ParseLBNumError, ParseLBNumErrorKind};
+
+/// Returns an iterator over the base-128 digits of a primitive integer, in little-endian order, without any trailing zeros.
+#[inline]
+pub(crate) fn u128_digits(mut op: u128) -> impl Iterator<Item = u8> + Clone {
+    iter::from_fn(move || {
+        if op == 0 {return None;}
+        let digit = (op & 0x7F) as u8;
+        op >>= 7;
+        Some(digit)
+    })
+}
 
 impl PartialEq for LBNum {
     #[inline(always)]
@@ -82,30 +100,40 @@ impl<'r> PartialOrd<LBNum> for LBNumRef<'r> {
     }
 }
 
+/// Compares a borrowed number to a primitive integer without allocating: if the number doesn't fit into the primitive type, it's larger than any value of it.
+macro_rules! cmp_to_primitive {
+    ($lhs:expr, $rhs:expr, $ty:ident) => {
+        match $ty::try_from($lhs) {
+            Ok(lhs) => lhs.cmp($rhs),
+            Err(_) => Ordering::Greater,
+        }
+    };
+}
+
 macro_rules! impl_compare_to_primitive {
     ($($ty:ident)+) => ($(
         impl PartialEq<$ty> for LBNum {
             #[inline(always)]
             fn eq(&self, rhs: &$ty) -> bool {
-                *self == Self::from(*rhs)
+                self.borrow() == *rhs
             }
         }
         impl PartialEq<$ty> for LBNumRef<'_> {
             #[inline(always)]
-            fn eq(&self, rhs:&$ty) -> bool {
-                *self == LBNum::from(*rhs)
+            fn eq(&self, rhs: &$ty) -> bool {
+                cmp_to_primitive!(*self, rhs, $ty) == Ordering::Equal
             }
         }
         impl PartialEq<LBNum> for $ty {
             #[inline(always)]
             fn eq(&self, rhs: &LBNum) -> bool {
-                LBNum::from(*self) == *rhs
+                rhs == self
             }
         }
         impl PartialEq<LBNumRef<'_>> for $ty {
             #[inline(always)]
             fn eq(&self, rhs: &LBNumRef<'_>) -> bool {
-                LBNum::from(*self) == *rhs
+                rhs == self
             }
         }
         impl PartialOrd<$ty> for LBNum {
@@ -114,7 +142,7 @@ macro_rules! impl_compare_to_primitive {
             /// Never fails, a return value of `Some` can be relied upon.
             #[inline(always)]
             fn partial_cmp(&self, rhs: &$ty) -> Option<Ordering> {
-                Some(self.cmp(&Self::from(*rhs)))
+                self.borrow().partial_cmp(rhs)
             }
         }
         impl PartialOrd<$ty> for LBNumRef<'_> {
@@ -123,7 +151,7 @@ macro_rules! impl_compare_to_primitive {
             /// Never fails, a return value of `Some` can be relied upon.
             #[inline(always)]
             fn partial_cmp(&self, rhs: &$ty) -> Option<Ordering> {
-                self.partial_cmp(&LBNum::from(*rhs)) // Why doesn't Ord have a type parameter?
+                Some(cmp_to_primitive!(*self, rhs, $ty)) // Why doesn't Ord have a type parameter?
             }
         }
         impl PartialOrd<LBNum> for $ty {
@@ -132,7 +160,7 @@ macro_rules! impl_compare_to_primitive {
             /// Never fails, a return value of `Some` can be relied upon.
             #[inline(always)]
             fn partial_cmp(&self, rhs: &LBNum) -> Option<Ordering> {
-                Some(LBNum::from(*self).cmp(rhs))
+                rhs.partial_cmp(self).map(Ordering::reverse)
             }
         }
         impl PartialOrd<LBNumRef<'_>> for $ty {
@@ -141,7 +169,7 @@ macro_rules! impl_compare_to_primitive {
             /// Never fails, a return value of `Some` can be relied upon.
             #[inline(always)]
             fn partial_cmp(&self, rhs: &LBNumRef<'_>) -> Option<Ordering> {
-                LBNum::from(*self).partial_cmp(rhs)
+                rhs.partial_cmp(self).map(Ordering::reverse)
             }
         }
     )+)
@@ -149,4 +177,4 @@ macro_rules! impl_compare_to_primitive {
 
 impl_compare_to_primitive! {
     u8 u16 u32 u64 u128 usize
-}
\ No newline at end of file
+}
@@ -1,13 +1,222 @@
 #![cfg_attr(feature = "clippy", allow(clippy::use_self))] // Multiplication impl blocks do this intentionally.
 
-use crate::{
-    linkedbytes::{LBNum, LBNumRef},
-    AddAssignAt,
-};
+use crate::linkedbytes::{LBNum, LBNumRef, LinkedByte};
+use super::div::short_division;
 use core::{
     ops,
-    mem::swap,
+    cmp::Ordering,
 };
+use alloc::{vec, vec::Vec};
+
+/// Operands with fewer digits than this (in either of them) are multiplied using the schoolbook algorithm.
+const KARATSUBA_THRESHOLD: usize = 32;
+/// Operands with at least this many digits (in both of them) are multiplied using the Toom-3 algorithm.
+const TOOM_3_THRESHOLD: usize = 256;
+
+/// Removes the zeros at the most significant end of a little-endian digit sequence.
+fn trim(mut op: Vec<u8>) -> Vec<u8> {
+    while op.last() == Some(&0) {op.pop();}
+    op
+}
+/// Compares two trimmed little-endian digit sequences.
+fn cmp_digits(lhs: &[u8], rhs: &[u8]) -> Ordering {
+    lhs.len().cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+/// Adds `rhs`, shifted to the left by `offset` digits, to `lhs` in place.
+fn add_digits_at(lhs: &mut Vec<u8>, offset: usize, rhs: &[u8]) {
+    if lhs.len() < offset + rhs.len() {
+        lhs.resize(offset + rhs.len(), 0);
+    }
+    let mut carry = 0_u16;
+    let mut i = offset;
+    for &digit in rhs {
+        let sum = lhs[i] as u16 + digit as u16 + carry;
+        lhs[i] = (sum & 0x7F) as u8;
+        carry = sum >> 7;
+        i += 1;
+    }
+    while carry != 0 {
+        if i == lhs.len() {lhs.push(0);}
+        let sum = lhs[i] as u16 + carry;
+        lhs[i] = (sum & 0x7F) as u8;
+        carry = sum >> 7;
+        i += 1;
+    }
+}
+/// Subtracts `rhs` from `lhs`, which must be larger than or equal to it.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // The borrow brings every difference into the digit range before it's stored.
+fn sub_digits(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let mut result = lhs.to_vec();
+    let mut borrow = 0_i16;
+    for (i, digit) in result.iter_mut().enumerate() {
+        let subtrahend = rhs.get(i).copied().unwrap_or(0) as i16 + borrow;
+        if subtrahend == 0 && i >= rhs.len() {break;}
+        let mut difference = *digit as i16 - subtrahend;
+        borrow = 0;
+        if difference < 0 {
+            difference += 128;
+            borrow = 1;
+        }
+        *digit = difference as u8;
+    }
+    trim(result)
+}
+
+/// Multiplies two little-endian base-128 digit sequences, picking the fastest algorithm for their sizes. The result has no zeros at the most significant end.
+pub(crate) fn mul_digits(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let (lhs, rhs) = if lhs.len() >= rhs.len() {(lhs, rhs)} else {(rhs, lhs)};
+    if rhs.is_empty() {
+        Vec::new()
+    } else if rhs.len() < KARATSUBA_THRESHOLD {
+        schoolbook(lhs, rhs)
+    } else if rhs.len() * 2 <= lhs.len() {
+        unbalanced(lhs, rhs)
+    } else if rhs.len() < TOOM_3_THRESHOLD {
+        karatsuba(lhs, rhs)
+    } else {
+        toom_3(lhs, rhs)
+    }
+}
+/// The schoolbook multiplication algorithm, with quadratic complexity but the smallest overhead.
+fn schoolbook(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    // Each product is less than 2^14, so the accumulators can't overflow unless the operands have hundreds of thousands of digits, which never reach this function.
+    let mut accumulators = vec![0_u32; lhs.len() + rhs.len()];
+    for (i, &left) in lhs.iter().enumerate() {
+        if left == 0 {continue;}
+        for (accumulator, &right) in accumulators[i..].iter_mut().zip(rhs) {
+            *accumulator += left as u32 * right as u32;
+        }
+    }
+    let mut result = Vec::with_capacity(accumulators.len());
+    let mut carry = 0_u32;
+    for accumulator in accumulators {
+        let sum = accumulator + carry;
+        result.push((sum & 0x7F) as u8);
+        carry = sum >> 7;
+    }
+    debug_assert_eq!(carry, 0);
+    trim(result)
+}
+/// Multiplies a long number by a much shorter one by splitting the longer one into chunks of the same size as the shorter one, which keeps the recursive algorithms balanced.
+fn unbalanced(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let mut result = Vec::with_capacity(lhs.len() + rhs.len());
+    for (i, chunk) in lhs.chunks(rhs.len()).enumerate() {
+        add_digits_at(&mut result, i * rhs.len(), &mul_digits(chunk, rhs));
+    }
+    trim(result)
+}
+/// The [Karatsuba multiplication algorithm][0], performing 3 multiplications of half size instead of 4.
+///
+/// [0]: https://en.wikipedia.org/wiki/Karatsuba_algorithm "Karatsuba algorithm on Wikipedia"
+fn karatsuba(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let half = lhs.len().div_ceil(2);
+    let (lhs_low, lhs_high) = lhs.split_at(half);
+    let (rhs_low, rhs_high) = rhs.split_at(half.min(rhs.len()));
+    let (lhs_low, rhs_low) = (trim(lhs_low.to_vec()), trim(rhs_low.to_vec()));
+
+    let low = mul_digits(&lhs_low, &rhs_low);
+    let high = mul_digits(lhs_high, rhs_high);
+    let mut lhs_sum = lhs_low; add_digits_at(&mut lhs_sum, 0, lhs_high);
+    let mut rhs_sum = rhs_low; add_digits_at(&mut rhs_sum, 0, rhs_high);
+    // (a + b)(c + d) - ac - bd = ad + bc
+    let middle = mul_digits(&lhs_sum, &rhs_sum);
+    let middle = sub_digits(&sub_digits(&middle, &low), &high);
+
+    let mut result = low;
+    result.reserve(lhs.len() + rhs.len());
+    add_digits_at(&mut result, half, &middle);
+    add_digits_at(&mut result, half * 2, &high);
+    trim(result)
+}
+
+/// A signed number used for the intermediate values of Toom-3 multiplication.
+#[derive(Clone, Debug, Default)]
+struct Signed {
+    negative: bool,
+    magnitude: Vec<u8>,
+}
+impl Signed {
+    fn new(negative: bool, magnitude: Vec<u8>) -> Self {
+        let magnitude = trim(magnitude);
+        Self {negative: negative && !magnitude.is_empty(), magnitude}
+    }
+    fn add(&self, rhs: &Self) -> Self {
+        if self.negative == rhs.negative {
+            let mut magnitude = self.magnitude.clone();
+            add_digits_at(&mut magnitude, 0, &rhs.magnitude);
+            Self::new(self.negative, magnitude)
+        } else {
+            match cmp_digits(&self.magnitude, &rhs.magnitude) {
+                Ordering::Less => Self::new(rhs.negative, sub_digits(&rhs.magnitude, &self.magnitude)),
+                _ => Self::new(self.negative, sub_digits(&self.magnitude, &rhs.magnitude)),
+            }
+        }
+    }
+    fn neg(&self) -> Self {
+        Self::new(!self.negative, self.magnitude.clone())
+    }
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+    fn mul(&self, rhs: &Self) -> Self {
+        Self::new(self.negative != rhs.negative, mul_digits(&self.magnitude, &rhs.magnitude))
+    }
+    fn double(&self) -> Self {
+        self.add(self)
+    }
+    /// Divides by a small number which is known to divide this one without a remainder.
+    fn div_exact(&self, rhs: u8) -> Self {
+        let (quotient, remainder) = short_division(&self.magnitude, rhs);
+        debug_assert_eq!(remainder, 0);
+        Self::new(self.negative, quotient)
+    }
+}
+/// The [Toom-3 multiplication algorithm][0], performing 5 multiplications of a third of the size instead of 9. Uses the evaluation points and interpolation sequence by Marco Bodrato.
+///
+/// [0]: https://en.wikipedia.org/wiki/Toom%E2%80%93Cook_multiplication "Toom-Cook multiplication on Wikipedia"
+fn toom_3(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    let third = lhs.len().div_ceil(3);
+    let split = |op: &[u8]| -> [Signed; 3] {
+        let mut parts = op.chunks(third).map(|part| Signed::new(false, part.to_vec()));
+        [
+            parts.next().unwrap_or_default(),
+            parts.next().unwrap_or_default(),
+            parts.next().unwrap_or_default(),
+        ]
+    };
+    // Evaluate both polynomials at 0, 1, -1, -2 and infinity.
+    let evaluate = |[m0, m1, m2]: [Signed; 3]| -> [Signed; 5] {
+        let m0_plus_m2 = m0.add(&m2);
+        let at_1 = m0_plus_m2.add(&m1);
+        let at_minus_1 = m0_plus_m2.sub(&m1);
+        let at_minus_2 = at_minus_1.add(&m2).double().sub(&m0);
+        [m0, at_1, at_minus_1, at_minus_2, m2]
+    };
+    let [p0, p1, pm1, pm2, pinf] = evaluate(split(lhs));
+    let [q0, q1, qm1, qm2, qinf] = evaluate(split(rhs));
+    let r0 = p0.mul(&q0);
+    let r1 = p1.mul(&q1);
+    let rm1 = pm1.mul(&qm1);
+    let rm2 = pm2.mul(&qm2);
+    let rinf = pinf.mul(&qinf);
+
+    // Interpolate the coefficients of the product.
+    let r3 = rm2.sub(&r1).div_exact(3);
+    let r1 = r1.sub(&rm1).div_exact(2);
+    let r2 = rm1.sub(&r0);
+    let r3 = r2.sub(&r3).div_exact(2).add(&rinf.double());
+    let r2 = r2.add(&r1).sub(&rinf);
+    let r1 = r1.sub(&r3);
+
+    let mut result = Vec::with_capacity(lhs.len() + rhs.len());
+    for (i, coefficient) in [r0, r1, r2, r3, rinf].iter().enumerate() {
+        // All the coefficients of the product are non-negative, since both operands are.
+        debug_assert!(!coefficient.negative);
+        add_digits_at(&mut result, i * third, &coefficient.magnitude);
+    }
+    trim(result)
+}
 
 // Implementation checklist:
 // | lhs | rhs | LBNumRef | reference | value | coreint |
@@ -25,20 +234,9 @@ impl<'l, 'r> ops::Mul<LBNumRef<'r>> for LBNumRef<'l> {
     /// Multiplies an `LBNumRef` **by another `LBNumRef`**.
     #[inline]
     fn mul(self, rhs: LBNumRef<'r>) -> LBNum {
-        let mut result = LBNum::ZERO;
-        let (mut left, mut right) = (self, rhs);
-        if right.0.len() > left.0.len() {
-            // This swaps the borrows so that the left one always has more bytes
-            swap(&mut left, &mut right);
-        }
-        for (byte_on_left, byte_on_left_index) in left.iter_le().zip(0_usize..) {
-            for byte_on_right in right.iter_le() {
-                let terms_multiplied
-                    = (byte_on_left.into_int7() as u16) * (byte_on_right.into_int7() as u16);
-                result.add_assign_at(byte_on_left_index, terms_multiplied);
-            }
-        }
-        result
+        let lhs = self.iter_le().map(LinkedByte::value).collect::<Vec<_>>();
+        let rhs = rhs.iter_le().map(LinkedByte::value).collect::<Vec<_>>();
+        LBNum::from_digits(mul_digits(&lhs, &rhs))
     }
 }
 // LBNumRef by reference
@@ -61,7 +259,7 @@ impl<'l> ops::Mul<LBNum> for LBNumRef<'l> {
 }
 
 // Reference by LBNumRef
-impl<'l, 'r> ops::Mul<LBNumRef<'r>> for &LBNum {
+impl<'r> ops::Mul<LBNumRef<'r>> for &LBNum {
     type Output = LBNum;
     // Multiplies an `LBNum` *reference* **by an `LBNumRef`**.
     #[inline(always)]
@@ -70,7 +268,7 @@ impl<'l, 'r> ops::Mul<LBNumRef<'r>> for &LBNum {
     }
 }
 // Reference by reference
-impl<'l, 'r> ops::Mul<&'r LBNum> for &'l LBNum {
+impl<'r> ops::Mul<&'r LBNum> for &LBNum {
     type Output = LBNum;
     // Multiplies an `LBNum` *reference* **by another `LBNum` reference.**
     #[inline(always)]
@@ -79,7 +277,7 @@ impl<'l, 'r> ops::Mul<&'r LBNum> for &'l LBNum {
     }
 }
 // Reference by value
-impl<'l> ops::Mul<LBNum> for &'l LBNum {
+impl ops::Mul<LBNum> for &LBNum {
     type Output = LBNum;
     /// Multiplies an `LBNum` *reference* **by an `LBNum`, consuming it.** Borrow the righthand operand (via normal borrow or `.borrow()`) to avoid such behavior.
     #[inline(always)]
@@ -149,13 +347,9 @@ macro_rules! impl_mul_by_primitive {
             #[doc = "Multiplies an `LBNumRef` **by a scalar integer.**"]
             #[inline]
             fn mul(self, rhs: $ty) -> LBNum {
-                let mut result = LBNum::ZERO;
-                for byte_on_left in self.iter_le() {
-                    let terms_multiplied
-                        = ((byte_on_left.into_int7() as $ty) * rhs);
-                    result += terms_multiplied;
-                }
-                result
+                let lhs = self.iter_le().map(LinkedByte::value).collect::<Vec<_>>();
+                let rhs = super::u128_digits(rhs as u128).collect::<Vec<_>>();
+                LBNum::from_digits(mul_digits(&lhs, &rhs))
             }
         }
         // Reference by int
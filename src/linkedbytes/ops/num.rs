@@ -0,0 +1,215 @@
+//! Implementations of the traits from `num-traits`.
+
+use super::fromstr::ParseLBNumError;
+use crate::linkedbytes::LBNum;
+use core::convert::TryFrom;
+use num_traits::{
+    Zero, One, Num, Unsigned, Pow,
+    CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, CheckedRem,
+    Saturating, SaturatingAdd, SaturatingSub, SaturatingMul,
+    ToPrimitive, FromPrimitive,
+};
+
+impl Zero for LBNum {
+    #[inline(always)]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.num_bytes() == 0
+    }
+}
+impl One for LBNum {
+    #[inline(always)]
+    fn one() -> Self {
+        Self::from(1_u8)
+    }
+    #[inline(always)]
+    fn is_one(&self) -> bool {
+        *self == 1_u8
+    }
+}
+impl Num for LBNum {
+    type FromStrRadixErr = ParseLBNumError;
+    #[inline(always)]
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseLBNumError> {
+        Self::from_str_radix(src, radix)
+    }
+}
+impl Unsigned for LBNum {}
+
+impl CheckedAdd for LBNum {
+    /// Adds two numbers. This never fails, since Linked Bytes numbers can grow indefinitely.
+    #[inline(always)]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+}
+impl CheckedSub for LBNum {
+    /// Subtracts two numbers, returning `None` if the result would be negative.
+    #[inline(always)]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.clone().checked_sub(rhs)
+    }
+}
+impl CheckedMul for LBNum {
+    /// Multiplies two numbers. This never fails, since Linked Bytes numbers can grow indefinitely.
+    #[inline(always)]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+}
+impl CheckedDiv for LBNum {
+    /// Divides two numbers, returning `None` if the divisor is zero.
+    #[inline]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {None} else {Some(self.clone() / rhs)}
+    }
+}
+impl CheckedRem for LBNum {
+    /// Calculates the remainder of dividing two numbers, returning `None` if the divisor is zero.
+    #[inline]
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        if rhs.is_zero() {None} else {Some(self.clone() % rhs)}
+    }
+}
+
+impl Saturating for LBNum {
+    /// Adds two numbers. Since there's no upper limit, this is the same as normal addition.
+    #[inline(always)]
+    fn saturating_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+    /// Subtracts two numbers, returning zero if the result would be negative.
+    #[inline(always)]
+    fn saturating_sub(self, rhs: Self) -> Self {
+        self.checked_sub(&rhs).unwrap_or(Self::ZERO)
+    }
+}
+impl SaturatingAdd for LBNum {
+    #[inline(always)]
+    fn saturating_add(&self, rhs: &Self) -> Self {
+        self + rhs
+    }
+}
+impl SaturatingSub for LBNum {
+    #[inline(always)]
+    fn saturating_sub(&self, rhs: &Self) -> Self {
+        self.clone().checked_sub(rhs).unwrap_or(Self::ZERO)
+    }
+}
+impl SaturatingMul for LBNum {
+    #[inline(always)]
+    fn saturating_mul(&self, rhs: &Self) -> Self {
+        self * rhs
+    }
+}
+
+macro_rules! impl_pow {
+    ($($ty:ident)+) => ($(
+        impl Pow<$ty> for LBNum {
+            type Output = Self;
+            /// Raises the number to a power using exponentiation by squaring.
+            ///
+            /// See [`pow_lb`][0] for the details.
+            ///
+            /// [0]: #method.pow_lb "pow_lb — raises the number to the power of a Linked Bytes number"
+            #[inline(always)]
+            fn pow(self, rhs: $ty) -> Self {
+                self.pow_lb(&Self::from(rhs))
+            }
+        }
+        impl Pow<$ty> for &LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn pow(self, rhs: $ty) -> LBNum {
+                self.pow_lb(&LBNum::from(rhs))
+            }
+        }
+        impl Pow<&$ty> for LBNum {
+            type Output = Self;
+            #[inline(always)]
+            fn pow(self, rhs: &$ty) -> Self {
+                self.pow_lb(&Self::from(*rhs))
+            }
+        }
+        impl Pow<&$ty> for &LBNum {
+            type Output = LBNum;
+            #[inline(always)]
+            fn pow(self, rhs: &$ty) -> LBNum {
+                self.pow_lb(&LBNum::from(*rhs))
+            }
+        }
+    )+)
+}
+impl_pow! {u8 u16 u32 usize}
+
+impl ToPrimitive for LBNum {
+    #[inline(always)]
+    fn to_i64(&self) -> Option<i64> {
+        i64::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_u64(&self) -> Option<u64> {
+        u64::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_i128(&self) -> Option<i128> {
+        i128::try_from(self).ok()
+    }
+    #[inline(always)]
+    fn to_u128(&self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+    /// Converts the number to the nearest `f64`, or infinity if it's too large.
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        let (top, exp2) = self.top_bits();
+        Some(crate::float::to_f64(crate::Sign::Positive, top, exp2))
+    }
+    /// Converts the number to the nearest `f32`, or infinity if it's too large.
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        let (top, exp2) = self.top_bits();
+        Some(crate::float::to_f32(crate::Sign::Positive, top, exp2))
+    }
+}
+impl FromPrimitive for LBNum {
+    #[inline(always)]
+    fn from_i64(op: i64) -> Option<Self> {
+        u64::try_from(op).ok().map(Self::from)
+    }
+    #[inline(always)]
+    fn from_u64(op: u64) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    #[inline(always)]
+    fn from_i128(op: i128) -> Option<Self> {
+        u128::try_from(op).ok().map(Self::from)
+    }
+    #[inline(always)]
+    fn from_u128(op: u128) -> Option<Self> {
+        Some(Self::from(op))
+    }
+    /// Converts a float into a number, truncating the fractional part. Returns `None` for numbers which are -1 or less, infinities and NaN, while numbers between -1 and 0 are truncated to zero, just like for primitive integers.
+    fn from_f64(op: f64) -> Option<Self> {
+        if !op.is_finite() || op <= -1.0 {return None;}
+        let bits = op.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i32;
+        let exponent = biased_exponent - 1075;
+        let mantissa = if biased_exponent == 0 {
+            (bits & 0x000F_FFFF_FFFF_FFFF) << 1
+        } else {
+            (bits & 0x000F_FFFF_FFFF_FFFF) | 0x0010_0000_0000_0000
+        };
+        if exponent >= 0 {
+            let two = Self::from(2_u8);
+            Some(Self::from(mantissa) * two.pow(exponent.unsigned_abs()))
+        } else if exponent > -64 {
+            Some(Self::from(mantissa >> -exponent))
+        } else {
+            Some(Self::ZERO)
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use crate::linkedbytes::LBNum;
+use core::convert::TryFrom;
+use alloc::{vec, vec::Vec};
+
+impl LBNum {
+    /// Raises the number to the power of an exponent given by its bits, from the most significant one, using exponentiation by squaring. Used by both `pow` and `checked_pow`.
+    fn pow_bits(&self, bits: impl Iterator<Item = bool>) -> Self {
+        let mut result = Self::from(1_u8);
+        for bit in bits {
+            result = &result * &result;
+            if bit {result *= self;}
+        }
+        result
+    }
+    /// Raises the number to the specified power.
+    ///
+    /// Zero raised to the power of zero is one.
+    #[inline]
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn pow(&self, exponent: u32) -> Self {
+        let bit_length = 32 - exponent.leading_zeros();
+        self.pow_bits((0..bit_length).rev().map(|index| exponent & (1 << index) != 0))
+    }
+    /// Raises the number to the power of a Linked Bytes number, or returns `None` if the result would be too large to be stored in memory at all.
+    ///
+    /// Zero raised to the power of zero is one. Raising zero or one to any power never fails.
+    pub fn checked_pow(&self, exponent: &Self) -> Option<Self> {
+        if *self <= 1_u8 || *exponent == 0_u8 {
+            return Some(if *exponent == 0_u8 {Self::from(1_u8)} else {self.clone()});
+        }
+        // The result has at least this many bits, and none of the allocations may be larger than isize::MAX bytes.
+        let min_bits = u128::try_from(exponent).ok()?
+            .checked_mul(self.bit_length() as u128 - 1)?;
+        if min_bits / 7 > isize::MAX as u128 {return None;}
+        Some(self.pow_bits((0..exponent.bit_length()).rev().map(|index| exponent.bit(index))))
+    }
+    /// Raises the number to the power of a Linked Bytes number.
+    ///
+    /// # Panics
+    /// Panics if the result is too large to be stored in memory, see [`checked_pow`][0].
+    ///
+    /// [0]: #method.checked_pow "checked_pow — raises the number to the power of a Linked Bytes number, checking whether the result can be stored"
+    #[inline]
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn pow_lb(&self, exponent: &Self) -> Self {
+        self.checked_pow(exponent).expect("the result of exponentiation is too large to be stored")
+    }
+    /// Raises the number to the specified power modulo `modulus`, i.e. calculates `self.pow_lb(exponent) % modulus` without ever calculating the power itself.
+    ///
+    /// Odd moduli, which is the case for RSA and most other cryptographic uses, are handled using Montgomery multiplication, which replaces every division with cheap multiplications and shifts. Even moduli use regular square-and-multiply with a remainder operation after every step.
+    ///
+    /// This is **not** constant-time, and thus should not be used with secret exponents, since the timing can reveal them. Verifying signatures is fine, since all of the inputs are public.
+    ///
+    /// # Panics
+    /// Panics if the modulus is zero.
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn modpow(&self, exponent: &Self, modulus: &Self) -> Self {
+        assert!(*modulus != 0_u8, "attempt to calculate the remainder with a divisor of zero");
+        if *modulus == 1_u8 {return Self::ZERO;}
+        let base = self.clone() % modulus;
+        let bits = (0..exponent.bit_length()).rev().map(|index| exponent.bit(index));
+        if modulus.bit(0) {
+            return Montgomery::new(modulus).pow(&base, bits);
+        }
+        let mut result = Self::from(1_u8);
+        for bit in bits {
+            result = &result * &result % modulus;
+            if bit {result = result * &base % modulus;}
+        }
+        result
+    }
+}
+
+/// The bit width of the limbs used for Montgomery multiplication.
+const LIMB_BITS: usize = 32;
+
+/// Modular multiplication context for an odd modulus, using Montgomery reduction with `R = 2^(32n)`, where `n` is the number of 32-bit limbs in the modulus.
+///
+/// The numbers are stored as little-endian base-2³² limbs rather than base-128 digits, since that's what the hardware multiplies best.
+struct Montgomery {
+    /// The modulus itself.
+    modulus: LBNum,
+    /// The limbs of the modulus.
+    limbs: Vec<u32>,
+    /// `-modulus⁻¹ mod 2³²`.
+    inverse: u32,
+}
+impl Montgomery {
+    fn new(modulus: &LBNum) -> Self {
+        let limbs = to_limbs(modulus, 0);
+        // Newton's iteration doubles the number of correct low bits every step, starting with 3 bits which are correct for every odd number.
+        let mut inverse = limbs[0];
+        for _ in 0..4 {
+            inverse = inverse.wrapping_mul(2_u32.wrapping_sub(limbs[0].wrapping_mul(inverse)));
+        }
+        Self {modulus: modulus.clone(), limbs, inverse: inverse.wrapping_neg()}
+    }
+    /// Converts a number which is smaller than the modulus into the Montgomery form, i.e. multiplies it by `R` modulo the modulus.
+    fn to_montgomery(&self, op: &LBNum) -> Vec<u32> {
+        let shifted = op << (self.limbs.len() * LIMB_BITS);
+        to_limbs(&(shifted % &self.modulus), self.limbs.len())
+    }
+    /// Calculates `lhs × rhs × R⁻¹ mod modulus`, using the coarsely integrated operand scanning method.
+    #[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // Only the low 32 bits of each sum are stored, the rest is carried.
+    fn multiply(&self, lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+        let len = self.limbs.len();
+        let mut result = vec![0_u32; len + 2];
+        for &rhs_limb in rhs {
+            let mut carry = 0_u64;
+            for (dst, &lhs_limb) in result.iter_mut().zip(lhs) {
+                let sum = *dst as u64 + lhs_limb as u64 * rhs_limb as u64 + carry;
+                *dst = sum as u32;
+                carry = sum >> LIMB_BITS;
+            }
+            let sum = result[len] as u64 + carry;
+            result[len] = sum as u32;
+            result[len + 1] = (sum >> LIMB_BITS) as u32;
+
+            // Add a multiple of the modulus which makes the lowest limb zero, then drop that limb.
+            let factor = result[0].wrapping_mul(self.inverse) as u64;
+            let mut carry = (result[0] as u64 + factor * self.limbs[0] as u64) >> LIMB_BITS;
+            for index in 1..len {
+                let sum = result[index] as u64 + factor * self.limbs[index] as u64 + carry;
+                result[index - 1] = sum as u32;
+                carry = sum >> LIMB_BITS;
+            }
+            let sum = result[len] as u64 + carry;
+            result[len - 1] = sum as u32;
+            result[len] = result[len + 1] + (sum >> LIMB_BITS) as u32;
+        }
+        result.truncate(len + 1);
+        // The result is below twice the modulus, so a single subtraction is enough.
+        if result[len] != 0 || !less_than(&result[..len], &self.limbs) {
+            let mut borrow = 0_i64;
+            for (dst, &limb) in result.iter_mut().zip(&self.limbs) {
+                let difference = *dst as i64 - limb as i64 + borrow;
+                *dst = difference as u32;
+                borrow = difference >> LIMB_BITS;
+            }
+        }
+        result.truncate(len);
+        result
+    }
+    /// Raises a number which is smaller than the modulus to the power of an exponent given by its bits, from the most significant one.
+    fn pow(&self, base: &LBNum, bits: impl Iterator<Item = bool>) -> LBNum {
+        let base = self.to_montgomery(base);
+        let mut result = self.to_montgomery(&LBNum::from(1_u8));
+        for bit in bits {
+            result = self.multiply(&result, &result);
+            if bit {result = self.multiply(&result, &base);}
+        }
+        // Multiplying by 1 divides by R, converting the result back from the Montgomery form.
+        let mut one = vec![0_u32; self.limbs.len()];
+        one[0] = 1;
+        from_limbs(&self.multiply(&result, &one))
+    }
+}
+
+/// Converts a number into little-endian base-2³² limbs, padded with zeros to at least `min_len` limbs.
+fn to_limbs(op: &LBNum, min_len: usize) -> Vec<u32> {
+    let bytes = op.to_base256_le();
+    let mut limbs = bytes.chunks(4)
+        .map(|chunk| chunk.iter().rev().fold(0_u32, |limb, &byte| (limb << 8) | byte as u32))
+        .collect::<Vec<_>>();
+    if limbs.len() < min_len {limbs.resize(min_len, 0);}
+    limbs
+}
+/// Converts little-endian base-2³² limbs back into a number.
+fn from_limbs(op: &[u32]) -> LBNum {
+    let bytes = op.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>();
+    LBNum::from_base256_le(&bytes)
+}
+/// Compares two little-endian limb sequences of the same length.
+fn less_than(lhs: &[u32], rhs: &[u32]) -> bool {
+    lhs.iter().rev().lt(rhs.iter().rev())
+}
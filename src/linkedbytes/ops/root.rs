@@ -0,0 +1,36 @@
+use crate::linkedbytes::LBNum;
+
+impl LBNum {
+    /// Returns the square root of the number, rounded down.
+    #[inline]
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn isqrt(&self) -> Self {
+        self.nth_root(2)
+    }
+    /// Returns the `n`th root of the number, rounded down, using Newton's method.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    #[must_use = "this is an expensive non-in-place operation"]
+    pub fn nth_root(&self, n: u32) -> Self {
+        assert!(n != 0, "attempt to calculate the zeroth root of a number");
+        if n == 1 || *self <= 1_u8 {return self.clone();}
+        // The number is below 2ⁿ, so the root is 1. Newton's method would have to raise 2 to the power of `n - 1` to find that out, which is hopeless for a huge `n`.
+        if n as usize >= self.bit_length() {return Self::from(1_u8);}
+        // Start with a power of two which is never below the root, from which the iteration decreases monotonically until it reaches the result.
+        let mut root = Self::from(1_u8) << self.bit_length().div_ceil(n as usize);
+        loop {
+            let next = (&root * (n - 1) + self.clone() / root.pow(n - 1)) / n;
+            if next >= root {break root;}
+            root = next;
+        }
+    }
+    /// Returns `true` if the number is a square of an integer, `false` otherwise.
+    pub fn is_perfect_square(&self) -> bool {
+        // The least significant digit is the remainder of division by 128, which filters out most of the non-squares without doing any arithmetic.
+        let low = self.iter_le().next().map_or(0, |byte| byte.value() as u32);
+        if !(0..64).any(|root: u32| root * root % 128 == low) {return false;}
+        let root = self.isqrt();
+        &root * &root == *self
+    }
+}
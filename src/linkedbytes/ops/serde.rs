@@ -0,0 +1,60 @@
+//! Implementations of the `Serialize` and `Deserialize` traits from `serde`.
+//!
+//! Human-readable formats get the number as a decimal string, while binary formats get the Linked Bytes encoding as a byte array.
+
+use crate::{linkedbytes::LBNum, serde_bytes::visit_byte_seq};
+use core::fmt::{self, Formatter};
+use serde::{
+    Serialize, Serializer, Deserialize, Deserializer,
+    de::{self, Visitor, SeqAccess, Unexpected},
+};
+
+impl Serialize for LBNum {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            serializer.serialize_bytes(&self.to_bytes())
+        }
+    }
+}
+impl<'de> Deserialize<'de> for LBNum {
+    /// Deserializes a number from a decimal string (or an integer) in human-readable formats and from its Linked Bytes encoding otherwise.
+    ///
+    /// The encoding doesn't have to be in the canonical form, but it has to occupy the entire byte array.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            // Self-describing formats may store the number as a primitive number rather than a string.
+            deserializer.deserialize_any(LBNumVisitor)
+        } else {
+            deserializer.deserialize_bytes(LBNumVisitor)
+        }
+    }
+}
+
+struct LBNumVisitor;
+impl<'de> Visitor<'de> for LBNumVisitor {
+    type Value = LBNum;
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("a Linked Bytes number")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<LBNum, E> {
+        v.parse().map_err(E::custom)
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<LBNum, E> {
+        Ok(LBNum::from(v))
+    }
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<LBNum, E> {
+        Ok(LBNum::from(v))
+    }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<LBNum, E> {
+        match LBNum::decode_prefix(v) {
+            Ok((result, len)) if len == v.len() => Ok(result),
+            _ => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<LBNum, A::Error> {
+        self.visit_bytes(&visit_byte_seq(seq)?)
+    }
+}
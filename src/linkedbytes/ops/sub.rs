@@ -1,78 +1,63 @@
 use crate::linkedbytes::{LBNum, LBNumRef, LinkedByte};
-use core::{
-    ops::{Sub, SubAssign},
-    hint,
-    convert::TryInto,
-};
+use super::u128_digits;
+use core::ops::{Sub, SubAssign};
 
 impl LBNum {
-    /// Decrements the byte at the specified index and returns the type of result.
-    pub(crate) fn decrement_at_index(&mut self, index: usize) -> DecrementResult {
-        if self.0.is_empty() {return DecrementResult::EndedWithBorrow;}
-        if self.0.get(index).is_none() {return DecrementResult::NoSuchIndex;}
-        for i in index.. {
-            if let Some(refindex) = self.0.get_mut(i) {
-                let (val, wrap) = refindex.sub_with_borrow(LinkedByte::from(1));
-                *refindex = val;
-                if !wrap {return DecrementResult::Ok(false);}
-            } else {
-                return DecrementResult::EndedWithBorrow;
-            }
-        }
-        DecrementResult::Ok(true)
+    /// Subtracts a sequence of base-128 digits, given in little-endian order, from the number, with the least significant one of them landing at the specified digit index. Returns `true` if the subtraction underflowed, in which case `self` is left untouched.
+    ///
+    /// This is the core of every subtraction implementation for Linked Bytes, including the ones with primitive integers.
+    pub(crate) fn sub_digits_at<I>(&mut self, index: usize, rhs: I) -> bool
+    where I: IntoIterator<Item = u8>, I::IntoIter: Clone {
+        let rhs = rhs.into_iter();
+        // Do a dry run first to find out whether the borrow runs off the end, so that the digits are only touched if the subtraction succeeds.
+        if sub_digits(self.0.inner_mut(), index, rhs.clone(), false) {return true;}
+        sub_digits(self.0.inner_mut(), index, rhs, true);
+        self.zero_fold();
+        false
+    }
+    /// Decrements the digit at the specified index, borrowing from the higher digits if required. Returns `true` if the decrement underflowed.
+    #[inline(always)]
+    pub(crate) fn decrement_at_index(&mut self, index: usize) -> bool {
+        self.sub_digits_at(index, core::iter::once(1))
     }
 
     /// Performs checked subtraction. Returns `None` if the result underflowed 0, or the result wrapped in `Some` otherwise.
     #[inline(always)]
     pub fn checked_sub(mut self, rhs: &Self) -> Option<Self> {
-        unsafe{ if !self.checked_sub_assign(rhs.into()) {Some(self)} else {None} }
+        if self.checked_sub_assign(rhs.into()) {None} else {Some(self)}
     }
-    /// Performs checked subtraction, returning `true` if overflow occurred.
-    ///
-    /// # Safety
-    /// If `true` is returned, the value of `self` is undefined.
-    pub(crate) unsafe fn checked_sub_assign(&mut self, rhs: LBNumRef<'_>) -> bool {
-        if rhs.is_empty() {return true;}
-        if self.0.len() < rhs.0.len() {
-            self.convert_last_to_linked();
-            self.0.inner_mut().resize(rhs.0.len(), LinkedByte::ZERO_LINK);
-            self.ensure_last_is_end();
-        }
-        for (i, other) in (0..self.0.len()).zip(rhs.iter_le()) {
-            let this = &mut self.0.inner_mut()[i];
-            let (val, wrapped) = this.sub_with_borrow(other);
-            *this = val;
-            if wrapped {
-                if i == self.0.len() - 1 {
-                    // If we're right at the end, we screwed up.
-                    return true;
-                } else {
-                    // If not, decrement the next byte.
-                    match self.decrement_at_index(i + 1) {
-                        DecrementResult::Ok(_) => {},
-                        DecrementResult::EndedWithBorrow | DecrementResult::NoSuchIndex => {return false}
-                    }
-                }
-            } else {
-                self.zero_fold();
-                return false;
-            }
-        }
-        self.zero_fold();
-        false
+    /// Performs checked subtraction in place, returning `true` if overflow occurred. In such a case, the value of `self` is not modified.
+    #[inline(always)]
+    pub(crate) fn checked_sub_assign(&mut self, rhs: LBNumRef<'_>) -> bool {
+        self.sub_digits_at(0, rhs.0.iter().map(|digit| digit.value()))
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub(crate) enum DecrementResult {
-    /// The decrement-at-index operation was successful. The boolean parameter then describes whether wrapping occurred or not.
-    Ok(bool),
-    /// Index out of bounds.
-    NoSuchIndex,
-    /// The operation ended with a borrow, i.e. the number was too small to be decremented.
-    ///
-    /// In this case, the left operand is guaranteed to be zero.
-    EndedWithBorrow
+/// Subtracts `rhs` from `digits` starting at `index`, storing the difference only if `store` is `true`. Returns `true` if the subtraction underflowed.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation, clippy::cast_sign_loss))] // The borrow brings every difference into the digit range before it's stored.
+fn sub_digits(digits: &mut [LinkedByte], index: usize, mut rhs: impl Iterator<Item = u8>, store: bool) -> bool {
+    let mut borrow = 0_i16;
+    let mut i = index;
+    loop {
+        let digit = match rhs.next() {
+            Some(digit) => digit,
+            None if borrow != 0 => 0,
+            None => return false,
+        };
+        if digits.len() <= i {
+            if digit == 0 && borrow == 0 {i += 1; continue;}
+            // We're subtracting a non-zero digit from nothing, which is an underflow.
+            return true;
+        }
+        let mut difference = digits[i].value() as i16 - digit as i16 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += 128;
+            borrow = 1;
+        }
+        if store {digits[i] = LinkedByte::from((difference as u8, true));}
+        i += 1;
+    }
 }
 
 impl Sub<&Self> for LBNum {
@@ -107,10 +92,8 @@ impl SubAssign<&Self> for LBNum {
     /// Subtraction underflow is undefined for the Linked Bytes format, since it only specifies unsigned integers.
     #[inline(always)]
     fn sub_assign(&mut self, rhs: &Self) {
-        unsafe {
-            if !self.checked_sub_assign(rhs.into()) {
-                panic!("BigBit integer underflow");
-            }
+        if self.checked_sub_assign(rhs.into()) {
+            panic!("BigBit integer underflow");
         }
     }
 }
@@ -125,55 +108,8 @@ impl SubAssign<Self> for LBNum {
     }
 }
 
-pub(crate) trait CheckedSub<'a, Rhs = &'a Self>: CheckedSubAssign<'a, Rhs> + Sized {
-    /// Performs checked subtraction. Returns `None` if the result underflowed 0, or the result wrapped in `Some` otherwise.
-    #[inline(always)]
-    fn checked_sub(mut self, rhs: Rhs) -> Option<Self> {
-        if unsafe {CheckedSubAssign::checked_sub_assign(&mut self, rhs)} {Some(self)} else {None}
-    }
-}
-pub(crate) trait CheckedSubAssign<'a, Rhs = &'a Self> {
-    unsafe fn checked_sub_assign(&mut self, rhs: Rhs) -> bool;
-}
-
 macro_rules! impl_sub_with_primitive {
     ($($ty:ident)+) => ($(
-        impl CheckedSub<'_, $ty> for LBNum {}
-        impl CheckedSubAssign<'_, $ty> for LBNum {
-            /// Performs checked subtraction, returning `true` if overflow occurred.
-            ///
-            /// # Safety
-            /// If `true` is returned, the value of `self` is undefined.
-            unsafe fn checked_sub_assign(&mut self, rhs: $ty) -> bool {
-                if self.0.inner().get(0).is_none() {return true;} // Cannot subtract from 0.
-                let div_by = LinkedByte::MAX + 1;
-                let (rep, rem) = (rhs / div_by as $ty, rhs % div_by as $ty);
-                for _ in 0..rep {
-                    let (val, wrapped) = self.0.inner_mut()[0].sub_with_borrow(LinkedByte::from(127));
-                    if wrapped {
-                        match self.decrement_at_index(1) {
-                            DecrementResult::Ok(_) => {},
-                            DecrementResult::EndedWithBorrow | DecrementResult::NoSuchIndex => {return true;},
-                        }
-                    }
-                    *self.0.get_mut(0).unwrap_or_else(|| hint::unreachable_unchecked() ) = val;
-                }
-                let rem: u8 = rem.try_into().unwrap();
-                let (val, wrapped) = self.0.inner_mut()[0].sub_with_borrow(LinkedByte::from(rem));
-                if wrapped {
-                    match self.decrement_at_index(1) {
-                        DecrementResult::Ok(_) => {},
-                        DecrementResult::EndedWithBorrow | DecrementResult::NoSuchIndex => {return true;},
-                    }
-                }
-                *self.0.get_mut(0)
-                    .unwrap_or_else(|| hint::unreachable_unchecked() ) // Unreachable since we checked for this in the beginning.
-                    = val;
-                self.zero_fold();
-                false
-            }
-        }
-
         impl Sub<$ty> for LBNum {
             type Output = Self;
             /// Subtracts `rhs` from `self`.
@@ -186,13 +122,14 @@ macro_rules! impl_sub_with_primitive {
                 self
             }
         }
-        /// Subtracts `rhs` from `self` in place.
-        ///
-        /// # Panics
-        /// Subtraction underflow is undefined for the Linked Bytes format, since it only specifies unsigned integers.
         impl SubAssign<$ty> for LBNum {
+            /// Subtracts `rhs` from `self` in place.
+            ///
+            /// # Panics
+            /// Subtraction underflow is undefined for the Linked Bytes format, since it only specifies unsigned integers.
+            #[inline]
             fn sub_assign(&mut self, rhs: $ty) {
-                if unsafe {CheckedSubAssign::checked_sub_assign(self, rhs)} {
+                if self.sub_digits_at(0, u128_digits(rhs as u128)) {
                     panic!("BigBit integer underflow");
                 }
             }
@@ -202,4 +139,4 @@ macro_rules! impl_sub_with_primitive {
 
 impl_sub_with_primitive! {
     u8 u16 u32 u64 u128 usize
-}
\ No newline at end of file
+}
@@ -15,16 +15,10 @@ macro_rules! impl_from_lb_for_primitive {
             fn try_from(op: LBNumRef<'r>) -> Result<Self, TryFromIntError> {
                 if op.inner().len() > POWERS_OF_128.len() {return Err(TryFromIntError);}
                 let mut result: $ty = 0;
-                for (num, el) in op.inner().iter().enumerate() {
-                    let tbl_power = POWERS_OF_128[num];
-                    if let Ok(power) = $ty::try_from(tbl_power) {
-                        if let Some(val) = $ty::from(el.into_int7()).checked_mul(power) {
-                            if let Some(added) = result.checked_add(val) {
-                                result = added;
-                            } else {return Err(TryFromIntError);}
-                        }
-                    } else {return Err(TryFromIntError);}
-
+                for el in op.iter_be() {
+                    result = result.checked_mul(128)
+                        .and_then(|shifted| shifted.checked_add($ty::from(el.into_int7())))
+                        .ok_or(TryFromIntError)?;
                 }
                 Ok(result)
             }
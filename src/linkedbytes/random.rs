@@ -0,0 +1,138 @@
+//! Generating random Linked Bytes numbers using [`rand`].
+//!
+//! The distributions in this module produce numbers with a specific bit length, a specific encoded length or uniformly below a bound. The [`GenLBNum`][glbn] extension trait provides shortcuts for the most common cases directly on random number generators.
+//!
+//! [`rand`]: https://crates.io/crates/rand "rand on Crates.io"
+//! [glbn]: trait.GenLBNum.html "GenLBNum — extension methods for generating random Linked Bytes numbers"
+
+use crate::linkedbytes::LBNum;
+use alloc::vec;
+use rand::{Rng, distributions::Distribution};
+
+/// Generates a number uniformly distributed in the range from 0 to 2<sup>bits</sup>, excluding the upper bound.
+fn gen_bits<R: Rng + ?Sized>(rng: &mut R, bits: usize) -> LBNum {
+    let mut bytes = vec![0_u8; bits.div_ceil(8)];
+    rng.fill_bytes(&mut bytes);
+    if bits % 8 != 0 {
+        if let Some(top) = bytes.last_mut() {*top &= (1 << (bits % 8)) - 1;}
+    }
+    LBNum::from_base256_le(&bytes)
+}
+
+/// Numbers uniformly distributed in the range from 0 to 2<sup>bits</sup>, excluding the upper bound, i.e. numbers with *at most* the specified number of bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RandomBits(pub usize);
+impl Distribution<LBNum> for RandomBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> LBNum {
+        gen_bits(rng, self.0)
+    }
+}
+
+/// Numbers with *exactly* the specified number of bits, i.e. uniformly distributed in the range from 2<sup>bits - 1</sup> to 2<sup>bits</sup>, excluding the upper bound.
+///
+/// The only number with 0 bits is zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExactBits(pub usize);
+impl Distribution<LBNum> for ExactBits {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> LBNum {
+        if self.0 == 0 {return LBNum::ZERO;}
+        let mut result = gen_bits(rng, self.0 - 1);
+        result.set_bit(self.0 - 1, true);
+        result
+    }
+}
+
+/// Numbers which take exactly the specified number of bytes when encoded, uniformly distributed among all such numbers.
+///
+/// Since zero is encoded as a single byte, the numbers which take 1 byte range from 0 to 127, while longer ones always have a nonzero most significant digit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExactBytes(usize);
+impl ExactBytes {
+    /// Creates the distribution for the specified encoded length.
+    ///
+    /// # Panics
+    /// Panics if the length is zero, since every number takes at least one byte when encoded.
+    #[inline]
+    pub fn new(len: usize) -> Self {
+        assert!(len != 0, "every Linked Bytes number takes at least one byte");
+        Self(len)
+    }
+    /// Returns the encoded length of the numbers produced by the distribution.
+    #[inline(always)]
+    pub const fn encoded_len(self) -> usize {
+        self.0
+    }
+}
+impl Distribution<LBNum> for ExactBytes {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> LBNum {
+        let low_bits = (self.0 - 1) * 7;
+        let top = if self.0 == 1 {rng.gen_range(0..128_u8)} else {rng.gen_range(1..128_u8)};
+        gen_bits(rng, low_bits) | (LBNum::from(top) << low_bits)
+    }
+}
+
+/// Numbers uniformly distributed in the range from 0 to the specified bound, excluding the bound itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UniformBelow {
+    bound: LBNum,
+    bits: usize,
+}
+impl UniformBelow {
+    /// Creates the distribution for the specified upper bound.
+    ///
+    /// # Panics
+    /// Panics if the bound is zero, since there are no numbers below it.
+    pub fn new(bound: LBNum) -> Self {
+        assert!(bound != 0_u8, "cannot generate a number below zero");
+        let bits = (bound.clone() - 1_u8).bit_length();
+        Self {bound, bits}
+    }
+    /// Returns the upper bound of the distribution.
+    #[inline(always)]
+    pub const fn bound(&self) -> &LBNum {
+        &self.bound
+    }
+}
+impl Distribution<LBNum> for UniformBelow {
+    /// Samples a number using rejection sampling: numbers with as many bits as the largest allowed one are generated until one of them is below the bound, which takes less than 2 attempts on average.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> LBNum {
+        loop {
+            let candidate = gen_bits(rng, self.bits);
+            if candidate < self.bound {break candidate;}
+        }
+    }
+}
+
+/// Extension methods for generating random Linked Bytes numbers, implemented for every random number generator.
+///
+/// These are shortcuts for sampling the distributions from this module, which are more efficient when generating many numbers with the same parameters.
+pub trait GenLBNum: Rng {
+    /// Generates a number with at most the specified number of bits, see [`RandomBits`][0].
+    ///
+    /// [0]: struct.RandomBits.html "RandomBits — numbers with at most the specified number of bits"
+    #[inline]
+    fn gen_lbnum_bits(&mut self, bits: usize) -> LBNum {
+        gen_bits(self, bits)
+    }
+    /// Generates a number uniformly distributed below the specified bound, see [`UniformBelow`][0].
+    ///
+    /// # Panics
+    /// Panics if the bound is zero.
+    ///
+    /// [0]: struct.UniformBelow.html "UniformBelow — numbers uniformly distributed below a bound"
+    #[inline]
+    fn gen_lbnum_below(&mut self, bound: &LBNum) -> LBNum {
+        UniformBelow::new(bound.clone()).sample(self)
+    }
+    /// Generates a number uniformly distributed in the range from `low` to `high`, excluding `high`.
+    ///
+    /// # Panics
+    /// Panics if `low` is not less than `high`.
+    fn gen_lbnum_range(&mut self, low: &LBNum, high: &LBNum) -> LBNum {
+        assert!(low < high, "cannot generate a number in an empty range");
+        self.gen_lbnum_below(&(high.clone() - low)) + low
+    }
+}
+impl<R: Rng + ?Sized> GenLBNum for R {}
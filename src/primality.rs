@@ -0,0 +1,250 @@
+//! Primality testing and prime generation for Linked Bytes numbers.
+//!
+//! Numbers below 2⁶⁴ are tested deterministically using the Miller–Rabin test with a fixed set of bases, which is known to have no counterexamples in that range. Larger numbers are tested using the [Baillie–PSW test][BPSW], which combines a Miller–Rabin test with base 2 and a strong Lucas test. No composite number passing it is known, even though their existence hasn't been disproven; if that isn't enough, [`is_probable_prime`][ipp] performs additional Miller–Rabin rounds with random bases on top of it.
+//!
+//! Randomness is supplied by the caller via the [`RandomSource`][rs] trait, which keeps this module usable without `std` and without depending on any particular random number generator crate. [`SplitMix64`][sm] is provided as a simple seedable implementation. With the `rand` feature enabled, every random number generator from `rand` can be used as well.
+//!
+//! [BPSW]: https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test "Baillie–PSW primality test on Wikipedia"
+//! [ipp]: fn.is_probable_prime.html "is_probable_prime — performs the Baillie–PSW test along with additional Miller–Rabin rounds with random bases"
+//! [rs]: trait.RandomSource.html "RandomSource — a source of random numbers, supplied by the caller"
+//! [sm]: struct.SplitMix64.html "SplitMix64 — a simple seedable pseudorandom number generator"
+
+use crate::linkedbytes::LBNum;
+use core::convert::TryFrom;
+
+/// A source of random numbers, supplied by the caller to the functions which need randomness.
+///
+/// With the `rand` feature enabled, this is implemented for every random number generator from `rand`.
+///
+/// The quality of the random numbers only affects the distribution of the generated primes and the strength of the additional Miller–Rabin rounds, never the correctness of the results of the deterministic tests.
+pub trait RandomSource {
+    /// Returns the next random 64-bit number. All of the bits are expected to be uniformly distributed.
+    fn next_u64(&mut self) -> u64;
+}
+#[cfg(feature = "rand")]
+impl<R: rand::RngCore + ?Sized> RandomSource for R {
+    /// Uses any random number generator from `rand`.
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        rand::RngCore::next_u64(self)
+    }
+}
+
+/// A simple seedable pseudorandom number generator, useful for reproducible prime generation.
+///
+/// This is the SplitMix64 generator, which is fast and has good statistical properties, but is **not cryptographically secure**. Use a cryptographically secure generator for anything which has to stay secret, such as RSA keys.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64(u64);
+impl SplitMix64 {
+    /// Creates a generator with the specified seed.
+    #[inline(always)]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+}
+impl RandomSource for SplitMix64 {
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut result = self.0;
+        result = (result ^ (result >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        result = (result ^ (result >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        result ^ (result >> 31)
+    }
+}
+
+/// The primes which are used for trial division before running the actual tests. The first 12 of them are also the bases for the deterministic Miller–Rabin test, which is enough for every number below 2⁶⁴.
+const SMALL_PRIMES: [u8; 54] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193, 197, 199,
+    211, 223, 227, 229, 233, 239, 241, 251,
+];
+/// The number of bases used by the deterministic Miller–Rabin test.
+const DETERMINISTIC_BASES: usize = 12;
+
+/// Returns `true` if the number is prime, `false` otherwise.
+///
+/// The result is exact for numbers below 2⁶⁴. For larger numbers, the Baillie–PSW test is used, for which no counterexamples are known.
+pub fn is_prime(n: &LBNum) -> bool {
+    if let Ok(small) = u64::try_from(n) {
+        return is_prime_u64(small);
+    }
+    if SMALL_PRIMES.iter().any(|&prime| n.clone() % prime == 0_u8) {
+        return false;
+    }
+    is_strong_probable_prime(n, &LBNum::from(2_u8)) && is_strong_lucas_probable_prime(n)
+}
+/// Returns `true` if the number is probably prime, `false` if it's definitely composite.
+///
+/// This performs the same test as [`is_prime`][0], and then `rounds` additional Miller–Rabin rounds with random bases, each of which lets a composite number through with a probability of at most ¼.
+///
+/// [0]: fn.is_prime.html "is_prime — checks whether the number is prime"
+pub fn is_probable_prime<R: RandomSource + ?Sized>(n: &LBNum, rounds: u32, rng: &mut R) -> bool {
+    if !is_prime(n) {return false;}
+    if u64::try_from(n).is_ok() {return true;} // Already exact.
+    // The bases are in the range from 2 to n - 2.
+    let range = n.clone() - 3_u8;
+    (0..rounds).all(|_| {
+        let base = random_bits(n.bit_length(), rng) % &range + 2_u8;
+        is_strong_probable_prime(n, &base)
+    })
+}
+/// Returns the smallest prime which is larger than the specified number.
+pub fn next_prime(n: &LBNum) -> LBNum {
+    if *n < 2_u8 {return LBNum::from(2_u8);}
+    // Only odd candidates have to be checked from here on.
+    let mut candidate = n.clone() + 1_u8;
+    if !candidate.bit(0) {candidate += 1_u8;}
+    while !is_prime(&candidate) {
+        candidate += 2_u8;
+    }
+    candidate
+}
+/// Generates a random prime with exactly the specified number of bits, i.e. in the range from 2<sup>bits - 1</sup> to 2<sup>bits</sup>.
+///
+/// The same sequence of random numbers always produces the same prime, which means that seeding the random number generator makes the result reproducible.
+///
+/// # Panics
+/// Panics if `bits` is less than 2, since there are no primes with fewer bits.
+pub fn random_prime<R: RandomSource + ?Sized>(bits: usize, rng: &mut R) -> LBNum {
+    assert!(bits >= 2, "there are no primes with fewer than 2 bits");
+    loop {
+        let mut candidate = random_bits(bits, rng);
+        // The most significant bit makes sure that the number has the requested length, and the least significant one skips the even numbers.
+        candidate.set_bit(bits - 1, true);
+        candidate.set_bit(0, true);
+        if is_prime(&candidate) {break candidate;}
+    }
+}
+
+/// Generates a random number with at most the specified number of bits.
+fn random_bits<R: RandomSource + ?Sized>(bits: usize, rng: &mut R) -> LBNum {
+    let words = bits.div_ceil(64);
+    let mut result = LBNum::ZERO;
+    for _ in 0..words {
+        result <<= 64_u8;
+        result |= rng.next_u64();
+    }
+    result >> (words * 64 - bits)
+}
+
+/// Deterministic Miller–Rabin test for numbers which fit into 64 bits.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // The remainder is smaller than the modulus, which fits into 64 bits.
+fn is_prime_u64(n: u64) -> bool {
+    if n < 2 {return false;}
+    for &prime in &SMALL_PRIMES {
+        let prime = prime as u64;
+        if n == prime {return true;}
+        if n % prime == 0 {return false;}
+    }
+    // Everything below 257² is prime if it has no factors below 257.
+    if n < 257 * 257 {return true;}
+    let mul_mod = |lhs: u64, rhs: u64| (lhs as u128 * rhs as u128 % n as u128) as u64;
+    let pow_mod = |mut base: u64, mut exponent: u64| {
+        let mut result = 1;
+        while exponent != 0 {
+            if exponent & 1 == 1 {result = mul_mod(result, base);}
+            base = mul_mod(base, base);
+            exponent >>= 1;
+        }
+        result
+    };
+    let (twos, odd) = ((n - 1).trailing_zeros(), (n - 1) >> (n - 1).trailing_zeros());
+    SMALL_PRIMES[..DETERMINISTIC_BASES].iter().all(|&base| {
+        let mut x = pow_mod(base as u64, odd);
+        if x == 1 || x == n - 1 {return true;}
+        (1..twos).any(|_| {
+            x = mul_mod(x, x);
+            x == n - 1
+        })
+    })
+}
+
+/// Performs a single round of the Miller–Rabin test, i.e. checks whether an odd number larger than 2 is a strong probable prime to the specified base.
+pub(crate) fn is_strong_probable_prime(n: &LBNum, base: &LBNum) -> bool {
+    let n_minus_one = n.clone() - 1_u8;
+    let twos = n_minus_one.trailing_zeros().unwrap_or(0);
+    let mut x = base.modpow(&(&n_minus_one >> twos), n);
+    if x == 1_u8 || x == n_minus_one {return true;}
+    (1..twos).any(|_| {
+        x = &x * &x % n;
+        x == n_minus_one
+    })
+}
+
+/// Performs the strong Lucas probable prime test on an odd number larger than 2, with the parameters chosen using Selfridge's method: `D` is the first number in the sequence 5, -7, 9, -11, … for which the Jacobi symbol (D/n) is -1, `P` is 1 and `Q` is (1 - D)/4.
+pub(crate) fn is_strong_lucas_probable_prime(n: &LBNum) -> bool {
+    // Perfect squares would make the search below go on forever, since there's no such D for them.
+    if n.is_perfect_square() {return false;}
+    // D is stored as its absolute value `abs_d` along with the sign, and the arithmetic is performed modulo n, with negative numbers replaced by n minus their absolute value.
+    let mut abs_d = 5_u64;
+    let mut d_negative = false;
+    loop {
+        let d_mod_n = negate_if(LBNum::from(abs_d) % n, d_negative, n);
+        match jacobi(&d_mod_n, n) {
+            -1 => break,
+            0 if *n != abs_d => return false, // D shares a factor with n.
+            _ => {},
+        }
+        abs_d += 2;
+        d_negative = !d_negative;
+    }
+    let d_mod_n = negate_if(LBNum::from(abs_d) % n, d_negative, n);
+    // Q = (1 - D)/4, which is negative for positive D and positive for negative D.
+    let q = match d_negative {
+        true => LBNum::from((abs_d + 1) / 4) % n,
+        false => negate_if(LBNum::from((abs_d - 1) / 4) % n, true, n),
+    };
+
+    let mul = |lhs: &LBNum, rhs: &LBNum| lhs * rhs % n;
+    let sub = |lhs: &LBNum, rhs: &LBNum| (lhs.clone() + n - (rhs.clone() % n)) % n;
+    let half = |op: LBNum| if op.bit(0) {(op + n) >> 1_u8} else {op >> 1_u8};
+
+    // n + 1 = odd × 2^twos
+    let n_plus_one = n.clone() + 1_u8;
+    let twos = n_plus_one.trailing_zeros().unwrap_or(0);
+    let odd = &n_plus_one >> twos;
+    // U₁ = 1, V₁ = P = 1, Qᵏ = Q, then the index is doubled for every bit and incremented for the set ones.
+    let (mut u, mut v, mut q_k) = (LBNum::from(1_u8), LBNum::from(1_u8), q.clone());
+    for index in (0..odd.bit_length() - 1).rev() {
+        u = mul(&u, &v);
+        v = sub(&mul(&v, &v), &(&q_k << 1_u8));
+        q_k = mul(&q_k, &q_k);
+        if odd.bit(index) {
+            let new_u = half((u.clone() + &v) % n);
+            v = half((mul(&d_mod_n, &u) + v) % n);
+            u = new_u;
+            q_k = mul(&q_k, &q);
+        }
+    }
+    if u == 0_u8 || v == 0_u8 {return true;}
+    for _ in 1..twos {
+        v = sub(&mul(&v, &v), &(&q_k << 1_u8));
+        if v == 0_u8 {return true;}
+        q_k = mul(&q_k, &q_k);
+    }
+    false
+}
+
+/// Returns `n - op` if `negate` is `true` and `op` is nonzero, otherwise `op` as is.
+fn negate_if(op: LBNum, negate: bool, n: &LBNum) -> LBNum {
+    if negate && op != 0_u8 {n.clone() - op} else {op}
+}
+/// Calculates the Jacobi symbol (a/n) for an odd positive `n`.
+fn jacobi(a: &LBNum, n: &LBNum) -> i8 {
+    let (mut a, mut n) = (a.clone() % n, n.clone());
+    let mut result = 1;
+    while a != 0_u8 {
+        let twos = a.trailing_zeros().unwrap_or(0);
+        a >>= twos;
+        // (2/n) is -1 if n ≡ 3 or 5 (mod 8).
+        let n_mod_8 = n.iter_le().next().map_or(0, |byte| byte.value() & 7);
+        if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {result = -result;}
+        // Quadratic reciprocity: the sign flips if both are ≡ 3 (mod 4).
+        let a_mod_4 = a.iter_le().next().map_or(0, |byte| byte.value() & 3);
+        if a_mod_4 == 3 && n_mod_8 & 3 == 3 {result = -result;}
+        core::mem::swap(&mut a, &mut n);
+        a %= &n;
+    }
+    if n == 1_u8 {result} else {0}
+}
@@ -0,0 +1,16 @@
+//! Deserialization of byte arrays from formats which don't have a dedicated byte array type and represent them as sequences instead.
+
+use alloc::vec::Vec;
+use serde::de::SeqAccess;
+
+/// The largest number of bytes which are allocated upfront based on the length reported by the format, the same limit as `serde` uses for its own collections. Anything longer grows as the bytes arrive, so that a malicious length prefix can't make us allocate arbitrary amounts of memory.
+const MAX_PREALLOCATED: usize = 1024 * 1024;
+
+/// Collects a sequence of bytes into a vector, to be handed over to `visit_bytes`.
+pub(crate) fn visit_byte_seq<'de, A: SeqAccess<'de>>(mut seq: A) -> Result<Vec<u8>, A::Error> {
+    let mut bytes = Vec::with_capacity(seq.size_hint().map_or(0, |len| len.min(MAX_PREALLOCATED)));
+    while let Some(byte) = seq.next_element()? {
+        bytes.push(byte);
+    }
+    Ok(bytes)
+}
@@ -37,4 +37,1027 @@ fn lb_sub() {
             LinkedByte::from(127),
     ])).unwrap();
     assert_eq!(num, expected);
-}
\ No newline at end of file
+}
+/// Checks that small Extended Head Byte numbers are laid out exactly like Head Byte ones.
+#[test]
+fn ehb_matches_hb() {
+    use crate::{EHBNum, ExtHeadByte, extheadbyte::ExtExponent, headbyte::Exponent};
+
+    let exponent = Exponent::try_from(0b1000_0010).unwrap(); // 10^-2
+    let num = EHBNum::from_raw_parts(
+        ExtHeadByte::from(0b1000_0000),
+        Some(ExtExponent::from(exponent)),
+        vec![0x39, 0x30],
+    );
+    assert!(!num.is_extended());
+    let bytes = num.to_bytes();
+    assert_eq!(bytes, [0b11_000011, 0b1000_0010, 0x39, 0x30]);
+    let decoded = EHBNum::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.to_bytes(), bytes);
+    assert_eq!(decoded.exponent(), Some(&ExtExponent::from(exponent)));
+}
+
+#[test]
+fn ehb_extended() {
+    use crate::{EHBNum, ExtHeadByte, Sign, extheadbyte::{ExtExponent, EHBDecodeError}};
+
+    let exponent = ExtExponent::new(Sign::Negative, LBNum::from(200_u8));
+    let coefficients = (0..20).collect::<alloc::vec::Vec<u8>>();
+    let num = EHBNum::from_raw_parts(ExtHeadByte::ZERO, Some(exponent.clone()), coefficients.clone());
+    assert!(num.is_extended());
+    let bytes = num.to_bytes();
+    // Zigzag-encoded -200 is 399, which is 0b11_0001111 in base 128.
+    assert_eq!(bytes[..4], [0b01_111111, 0b1_0001111, 0b0_0000011, 20]);
+    let decoded = EHBNum::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded.exponent(), Some(&exponent));
+    assert!(decoded.coefficient_le_iter().eq(coefficients));
+
+    assert_eq!(EHBNum::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(), EHBDecodeError::UnexpectedEnd);
+    assert_eq!(EHBNum::from_bytes(&[0b00_010000]).unwrap_err(), EHBDecodeError::InvalidHeadByte);
+}
+
+#[test]
+fn hb_bytes() {
+    use crate::{HBNum, HeadByte, headbyte::{Exponent, HBDecodeError, InvalidExponentError}};
+
+    let num = HBNum::from_raw_parts(HeadByte::from(0b1000_0000), Some(Exponent::try_from(3).unwrap()), vec![0xFF, 0x01]);
+    let bytes = num.to_bytes();
+    assert_eq!(bytes, [0b11_000011, 3, 0xFF, 0x01]);
+    let (decoded, len) = HBNum::parse(&[0b11_000011, 3, 0xFF, 0x01, 0xAB]).unwrap();
+    assert_eq!(len, 4);
+    assert_eq!(decoded.to_bytes(), bytes);
+
+    assert_eq!(HBNum::from_bytes(&bytes[..3]).unwrap_err(), HBDecodeError::UnexpectedEnd);
+    assert_eq!(HBNum::from_bytes(&[0b01_000010, 0b1000_0000, 1]).unwrap_err(), HBDecodeError::InvalidExponent(InvalidExponentError));
+    assert_eq!(HBNum::from_bytes(&[0b00_000001, 1, 2]).unwrap_err(), HBDecodeError::TrailingBytes);
+    assert!(HBNum::from_bytes(&[HeadByte::NEG_INFINITY.into_inner()]).unwrap().headbyte().is_infinite());
+}
+
+#[test]
+fn lb_decode() {
+    let buffer = [0b1_0000000, 0b0_0000001, 0b0_0000000, 0b1_0000101, 0b0_0000000, 0b1_0000001];
+    let (first, len) = LBNum::decode_prefix(&buffer).unwrap();
+    assert_eq!((first, len), (LBNum::from(128_u8), 2));
+
+    let mut iter = LBNum::decode_iter(&buffer);
+    assert_eq!(iter.next(), Some(Ok(LBNum::from(128_u8))));
+    assert_eq!(iter.next(), Some(Ok(LBNum::ZERO)));
+    assert_eq!(iter.next(), Some(Ok(LBNum::from(5_u8)))); // The redundant zero byte is dropped.
+    assert_eq!(iter.next(), Some(Err(crate::linkedbytes::InvalidLBSequence)));
+    assert_eq!(iter.next(), None);
+
+    #[cfg(feature = "std")] {
+        let mut reader = &buffer[..];
+        assert_eq!(LBNum::read_from(&mut reader).unwrap(), 128_u8);
+        assert_eq!(reader.len(), 4);
+        let mut reader = &buffer[5..];
+        assert_eq!(LBNum::read_from(&mut reader).unwrap_err().kind(), std::io::ErrorKind::UnexpectedEof);
+    }
+}
+
+#[test]
+fn lb_ref_decode() {
+    use crate::linkedbytes::{LBNumRef, InvalidLBSequence};
+
+    let buffer = [0b1_0101100, 0b0_0000010, 0, 0b1_1111111, 0b1_1111111, 0b0_0000001, 0b1_0000101, 0];
+    let (first, len) = LBNumRef::from_bytes(&buffer).unwrap();
+    assert_eq!(len, 2);
+    assert_eq!(first, 300_u16);
+    assert!(first < 301_u16 && 299_u16 < first);
+    assert_eq!(u64::try_from(first), Ok(300));
+    assert_eq!(first, LBNum::from(300_u16));
+    assert_eq!(first.inner().as_ptr().cast::<u8>(), buffer.as_ptr()); // No copies.
+
+    let mut iter = LBNumRef::decode_iter(&buffer[2..]);
+    assert!(iter.next().unwrap().unwrap().is_empty()); // Zero is borrowed as an empty slice.
+    let big = iter.next().unwrap().unwrap();
+    assert_eq!(big, 0x7FFF_u16); // 2¹⁵ - 1
+    assert!(big > u8::MAX);
+    assert_eq!(iter.remainder(), [0b1_0000101, 0]);
+    assert_eq!(iter.next(), Some(Err(InvalidLBSequence))); // The redundant zero byte is not allowed.
+    assert_eq!(iter.next(), None);
+    assert_eq!(LBNumRef::from_bytes(&[0b1_0000001]).unwrap_err(), InvalidLBSequence);
+}
+
+#[test]
+fn lb_encode() {
+    let num = LBNum::from(300_u16); // 0b10_0101100
+    assert_eq!(num.encoded_len(), 2);
+    assert_eq!(num.to_bytes(), [0b1_0101100, 0b0_0000010]);
+    assert_eq!(LBNum::ZERO.encoded_len(), 1);
+    assert_eq!(LBNum::ZERO.to_bytes(), [0]);
+
+    let mut buffer = vec![0xFF];
+    num.encode_into(&mut buffer);
+    LBNum::ZERO.encode_into(&mut buffer);
+    assert_eq!(buffer, [0xFF, 0b1_0101100, 0b0_0000010, 0]);
+    #[cfg(feature = "std")] {
+        let mut written = alloc::vec::Vec::new();
+        num.write_to(&mut written).unwrap();
+        LBNum::ZERO.write_to(&mut written).unwrap();
+        assert_eq!(written, buffer[1..]);
+    }
+    assert_eq!(LBNum::decode_prefix(&buffer[1..]).unwrap(), (num, 2));
+}
+
+#[test]
+fn lb_div() {
+    use crate::DivRem;
+
+    let values: &[u128] = &[
+        0, 1, 2, 3, 127, 128, 129, 255, 16_383, 16_384, 1_000_000_007,
+        0xFFFF_FFFF_FFFF_FFFF, 0x1234_5678_9ABC_DEF0_0FED_CBA9_8765_4321, u128::MAX,
+    ];
+    for &lhs in values {
+        for &rhs in values.iter().filter(|&&rhs| rhs != 0) {
+            let (quotient, remainder) = LBNum::from(lhs).div_rem(&LBNum::from(rhs));
+            assert_eq!(quotient, lhs / rhs, "{lhs} / {rhs}");
+            assert_eq!(remainder, lhs % rhs, "{lhs} % {rhs}");
+            assert_eq!(LBNum::from(lhs) / rhs, lhs / rhs);
+        }
+    }
+    // This would take forever with repeated subtraction.
+    let huge = LBNum::from_sequence(
+        LBSequence::from(vec![LinkedByte::from(127); 1000])
+    ) + 1_u8;
+    let (quotient, remainder) = huge.div_rem(3_u8);
+    assert_eq!(remainder, 1_u8); // 128 ≡ 2 (mod 3), so 128^1000 ≡ 2^1000 ≡ 1 (mod 3)
+    assert_eq!(quotient.num_bytes(), 1000);
+}
+
+#[test]
+fn lb_mul() {
+    use crate::DivRem;
+
+    let values: &[u128] = &[
+        0, 1, 2, 127, 128, 200, 16_383, 16_384, 1_000_000_007, 0xFFFF_FFFF_FFFF_FFFF, u128::MAX,
+    ];
+    for &lhs in values {
+        for &rhs in values {
+            if let Some(product) = lhs.checked_mul(rhs) {
+                assert_eq!(LBNum::from(lhs) * LBNum::from(rhs), product, "{lhs} * {rhs}");
+                assert_eq!(LBNum::from(lhs) * rhs, product, "{lhs} * {rhs}");
+            }
+        }
+    }
+    // (128^a - 1)(128^b - 1) = 128^(a + b) - 128^a - 128^b + 1, for sizes hitting every algorithm.
+    let all_ones = |len| LBNum::from_sequence(
+        LBSequence::from(vec![LinkedByte::from(127); len])
+    );
+    let power = |exponent| all_ones(exponent) + 1_u8;
+    for &(a, b) in &[(5, 7), (40, 50), (300, 20), (300, 400), (1000, 999)] {
+        let expected = power(a + b) + 1_u8 - power(a) - power(b);
+        assert_eq!(all_ones(a) * all_ones(b), expected, "{a} by {b} digits");
+    }
+    // Pseudorandom operands, checked against division.
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+    let mut random = |len| LBNum::from_sequence(LBSequence::from(
+        (0..len).map(|_| {
+            state ^= state << 13; state ^= state >> 7; state ^= state << 17;
+            LinkedByte::from((state % 128) as u8)
+        }).collect::<alloc::vec::Vec<_>>()
+    ));
+    for &(a, b) in &[(33, 64), (100, 250), (257, 700), (800, 800)] {
+        let (lhs, rhs) = (random(a) + 1_u8, random(b) + 1_u8);
+        let (quotient, remainder) = (&lhs * &rhs).div_rem(&rhs);
+        assert_eq!(quotient, lhs);
+        assert_eq!(remainder, 0_u8);
+    }
+}
+
+#[test]
+fn lb_bitwise() {
+    let values: &[u128] = &[
+        0, 1, 2, 127, 128, 200, 16_383, 16_384, 1_000_000_007, 0xDEAD_BEEF_0000_FFFF, u128::MAX >> 1,
+    ];
+    for &lhs in values {
+        for &rhs in values {
+            assert_eq!(LBNum::from(lhs) & LBNum::from(rhs), lhs & rhs, "{lhs} & {rhs}");
+            assert_eq!(LBNum::from(lhs) | &LBNum::from(rhs), lhs | rhs, "{lhs} | {rhs}");
+            assert_eq!(LBNum::from(lhs) ^ rhs, lhs ^ rhs, "{lhs} ^ {rhs}");
+        }
+        let num = LBNum::from(lhs);
+        assert_eq!(num.count_ones(), lhs.count_ones() as usize);
+        assert_eq!(num.bit_length(), (128 - lhs.leading_zeros()) as usize);
+        assert_eq!(num.trailing_zeros(), if lhs == 0 {None} else {Some(lhs.trailing_zeros() as usize)});
+        for shift in [0_u32, 1, 6, 7, 8, 13, 14, 64] {
+            assert_eq!(&num >> shift, lhs >> shift, "{lhs} >> {shift}");
+            if let Some(shifted) = lhs.checked_shl(shift).filter(|shifted| shifted >> shift == lhs) {
+                assert_eq!(&num << shift, shifted, "{lhs} << {shift}");
+            }
+        }
+        for index in 0..128 {
+            assert_eq!(num.bit(index), lhs & (1 << index) != 0, "bit {index} of {lhs}");
+        }
+    }
+    // Shifts and bits beyond the primitive range.
+    let mut big = LBNum::from(1_u8) << 1000_usize;
+    assert_eq!(big.bit_length(), 1001);
+    assert_eq!(big.trailing_zeros(), Some(1000));
+    assert!(big.bit(1000) && !big.bit(999) && !big.bit(5000));
+    big.set_bit(3, true);
+    big.set_bit(5000, false);
+    assert_eq!(big.count_ones(), 2);
+    assert_eq!(&big >> 998_u16, 4_u8);
+    assert_eq!(&big ^ (LBNum::from(1_u8) << 1000_u32), 8_u8);
+    big.set_bit(1000, false);
+    assert_eq!(big, 8_u8);
+    assert_eq!(big.num_bytes(), 1); // The zeros left after clearing the top bit are removed.
+    assert_eq!(LBNum::from(5_u8) >> u128::MAX, 0_u8);
+    assert_eq!(LBNum::ZERO << 100_u8, 0_u8);
+}
+
+#[test]
+fn lb_pow() {
+    for base in [0_u128, 1, 2, 3, 127, 128, 1_000] {
+        for exponent in [0_u32, 1, 2, 5, 12] {
+            if let Some(power) = base.checked_pow(exponent) {
+                assert_eq!(LBNum::from(base).pow(exponent), power, "{base}^{exponent}");
+                assert_eq!(LBNum::from(base).pow_lb(&LBNum::from(exponent)), power, "{base}^{exponent}");
+            }
+            for modulus in [1_u128, 2, 7, 10, 128, 1_000_000_007, 0xFFFF_FFFF_FFFF_FFC5] {
+                let expected = (0..exponent).fold(1 % modulus, |power, _| power * (base % modulus) % modulus);
+                assert_eq!(LBNum::from(base).modpow(&LBNum::from(exponent), &LBNum::from(modulus)), expected, "{base}^{exponent} mod {modulus}");
+            }
+        }
+    }
+    assert!(LBNum::from(2_u8).checked_pow(&(LBNum::from(1_u8) << 200_u8)).is_none());
+    assert_eq!(LBNum::from(1_u8).checked_pow(&(LBNum::from(1_u8) << 200_u8)), Some(LBNum::from(1_u8)));
+
+    // Fermat's little theorem with the Mersenne primes 2¹²⁷ - 1 and 2⁵²¹ - 1, both odd and spanning several Montgomery limbs.
+    for bits in [127_u32, 521] {
+        let prime = (LBNum::from(1_u8) << bits) - 1_u8;
+        let base = LBNum::from(0xDEAD_BEEF_u32).pow(20);
+        assert_eq!(base.modpow(&(prime.clone() - 1_u8), &prime), 1_u8);
+        assert_eq!(base.modpow(&prime, &prime), base.clone() % &prime);
+    }
+    // A toy RSA key: signing with the private exponent and verifying with the public one gives back the message.
+    let (p, q) = ((LBNum::from(1_u8) << 127_u8) - 1_u8, (LBNum::from(1_u8) << 89_u8) - 1_u8);
+    let modulus = &p * &q;
+    let totient = (p - 1_u8) * (q - 1_u8);
+    let public = LBNum::from(65_537_u32);
+    let (_, (sign, private), _) = crate::extended_gcd(public.clone(), totient.clone());
+    let private = match sign {
+        crate::Sign::Positive => private,
+        crate::Sign::Negative => totient - private,
+    };
+    let message = LBNum::from(0x1234_5678_9ABC_DEF0_u64).pow(3);
+    let signature = message.modpow(&private, &modulus);
+    assert_eq!(signature.modpow(&public, &modulus), message);
+    // An even modulus, checked against the full power.
+    let modulus = LBNum::from(10_u8).pow(40);
+    assert_eq!(LBNum::from(3_u8).modpow(&LBNum::from(300_u16), &modulus), LBNum::from(3_u8).pow(300) % &modulus);
+}
+
+#[test]
+fn lb_roots() {
+    for value in [0_u128, 1, 2, 3, 4, 8, 9, 99, 100, 101, 127, 128, 1_000_000, 999_999_999_999, u128::MAX] {
+        let num = LBNum::from(value);
+        assert_eq!(num.ilog2(), value.checked_ilog2().map(|log| log as usize), "ilog2 {value}");
+        assert_eq!(num.ilog10(), value.checked_ilog10().map(|log| log as usize), "ilog10 {value}");
+        assert_eq!(num.checked_ilog(&LBNum::from(3_u8)), value.checked_ilog(3).map(|log| log as usize), "ilog3 {value}");
+        let sqrt = num.isqrt();
+        assert!(sqrt.pow(2) <= num && (sqrt.clone() + 1_u8).pow(2) > num, "isqrt {}", value);
+        assert_eq!(num.is_perfect_square(), sqrt.pow(2) == num, "is_perfect_square {value}");
+        if value <= 1_000_000 {
+            let cbrt = (0..=100).rev().find(|root: &u128| root.pow(3) <= value).unwrap();
+            assert_eq!(num.nth_root(3), cbrt, "cbrt {value}");
+        }
+        assert_eq!(num.checked_ilog(&LBNum::from(1_u8)), None);
+        assert_eq!(num.checked_ilog(&LBNum::ZERO), None);
+    }
+    let power = LBNum::from(10_u8).pow(500);
+    assert_eq!(power.ilog10(), Some(500));
+    assert_eq!((power.clone() - 1_u8).ilog10(), Some(499));
+    assert_eq!(power.isqrt(), LBNum::from(10_u8).pow(250));
+    assert!(power.is_perfect_square() && !(power.clone() + 1_u8).is_perfect_square());
+    let root = LBNum::from(10_u8).pow(100) + 7_u8;
+    assert_eq!((root.pow(7) - 1_u8).nth_root(7), root.clone() - 1_u8);
+    assert_eq!(root.pow(7).nth_root(7), root);
+    assert_eq!(LBNum::from(1000_u32).nth_root(u32::MAX), LBNum::from(1_u8));
+    assert_eq!(LBNum::from(1024_u32).nth_root(10), LBNum::from(2_u8));
+    assert_eq!(LBNum::from(1023_u32).nth_root(10), LBNum::from(1_u8));
+    assert_eq!(power.checked_ilog(&LBNum::from(1_000_u16)), Some(166));
+    #[allow(deprecated)] {
+        assert_eq!(LBNum::from(1_000_u16).logb(LBNum::from(10_u8)), 3_u8);
+        assert_eq!(LBNum::ZERO.logb(LBNum::from(10_u8)), 0_u8);
+    }
+}
+
+#[test]
+fn primality() {
+    use crate::primality::{self, SplitMix64};
+
+    let mut sieve = vec![true; 10_000];
+    sieve[0] = false;
+    sieve[1] = false;
+    for i in 2..100 {
+        if sieve[i] {(i * i..sieve.len()).step_by(i).for_each(|j| sieve[j] = false);}
+    }
+    for (n, &expected) in sieve.iter().enumerate() {
+        let num = LBNum::from(n);
+        assert_eq!(primality::is_prime(&num), expected, "is_prime {n}");
+        // 5459 and 5777 are the only strong Lucas pseudoprimes in this range.
+        if n > 2 && n % 2 == 1 {
+            assert_eq!(primality::is_strong_lucas_probable_prime(&num), expected || n == 5459 || n == 5777, "lucas {n}");
+        }
+    }
+    for pseudoprime in [5459_u16, 5777, 10877] {
+        assert!(primality::is_strong_lucas_probable_prime(&LBNum::from(pseudoprime)));
+        assert!(!primality::is_prime(&LBNum::from(pseudoprime)));
+    }
+    assert!(!primality::is_prime(&LBNum::from(561_u16))); // Carmichael number
+    assert!(!primality::is_prime(&LBNum::from(2047_u16))); // Strong pseudoprime to base 2
+    assert!(primality::is_prime(&LBNum::from(u64::MAX - 58))); // The largest 64-bit prime
+
+    let mersenne = |exponent: u32| LBNum::from(2_u8).pow(exponent) - 1_u8;
+    assert!(primality::is_prime(&mersenne(127)));
+    assert!(primality::is_prime(&mersenne(521)));
+    // 2⁶⁷ - 1 = 193707721 × 761838257287 is a strong pseudoprime to base 2, so only the Lucas test catches it.
+    let m67 = mersenne(67);
+    assert_eq!(LBNum::from(193_707_721_u32) * LBNum::from(761_838_257_287_u64), m67);
+    assert!(primality::is_strong_probable_prime(&m67, &LBNum::from(2_u8)));
+    assert!(!primality::is_prime(&m67));
+    assert!(!primality::is_prime(&(mersenne(127) * mersenne(89))));
+    let mut rng = SplitMix64::new(42);
+    assert!(primality::is_probable_prime(&mersenne(521), 8, &mut rng));
+    assert!(!primality::is_probable_prime(&m67, 8, &mut rng));
+
+    assert_eq!(primality::next_prime(&LBNum::ZERO), 2_u8);
+    assert_eq!(primality::next_prime(&LBNum::from(2_u8)), 3_u8);
+    assert_eq!(primality::next_prime(&LBNum::from(7_u8)), 11_u8);
+    assert_eq!(primality::next_prime(&LBNum::from(u64::MAX - 58)), LBNum::from(u64::MAX) + 14_u8); // 2⁶⁴ + 13
+    assert_eq!(primality::next_prime(&LBNum::from(2_u8).pow(127)), LBNum::from(2_u8).pow(127) + 29_u8);
+
+    for bits in [2, 3, 17, 64, 65, 200] {
+        let prime = primality::random_prime(bits, &mut SplitMix64::new(bits as u64));
+        assert_eq!(prime.bit_length(), bits);
+        assert!(primality::is_prime(&prime));
+        assert_eq!(primality::random_prime(bits, &mut SplitMix64::new(bits as u64)), prime);
+    }
+}
+
+#[test]
+fn lb_gcd() {
+    use crate::{Gcd, ExtendedGcd, Sign};
+
+    fn gcd_u128(mut lhs: u128, mut rhs: u128) -> u128 {
+        while rhs != 0 {
+            let remainder = lhs % rhs;
+            lhs = rhs;
+            rhs = remainder;
+        }
+        lhs
+    }
+    let values: &[u128] = &[
+        0, 1, 2, 6, 12, 18, 128, 240, 46, 1_000_000_007, 0xFFFF_FFFF_FFFF_FFFF, 1 << 100, u128::MAX,
+    ];
+    for &lhs in values {
+        for &rhs in values {
+            let expected = gcd_u128(lhs, rhs);
+            assert_eq!(LBNum::gcd(lhs.into(), rhs.into()), expected, "gcd({lhs}, {rhs})");
+            if let Some(lcm) = (lhs / expected.max(1)).checked_mul(rhs) {
+                assert_eq!(LBNum::lcm(lhs.into(), rhs.into()), lcm, "lcm({lhs}, {rhs})");
+            }
+            // lhs·x + rhs·y = gcd, checked by moving the negative term to the other side.
+            let (gcd, (x_sign, x), (y_sign, y)) = LBNum::extended_gcd(lhs.into(), rhs.into());
+            assert_eq!(gcd, expected);
+            let (lhs_term, rhs_term) = (LBNum::from(lhs) * x, LBNum::from(rhs) * y);
+            match (x_sign, y_sign) {
+                (Sign::Positive, Sign::Positive) => assert_eq!(lhs_term + rhs_term, gcd),
+                (Sign::Positive, Sign::Negative) => assert_eq!(lhs_term, rhs_term + gcd),
+                (Sign::Negative, Sign::Positive) => assert_eq!(rhs_term, lhs_term + gcd),
+                (Sign::Negative, Sign::Negative) => panic!("both coefficients are negative"),
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "num_traits")]
+fn lb_num_traits() {
+    use num_traits::{Num, Zero, One, Pow, CheckedSub, CheckedDiv, Saturating, ToPrimitive, FromPrimitive};
+
+    assert!(LBNum::zero().is_zero());
+    assert!(LBNum::one().is_one());
+    assert_eq!(<LBNum as Num>::from_str_radix("ff", 16).unwrap(), 255_u8);
+    let big = <LBNum as Num>::from_str_radix("340282366920938463463374607431768211455", 10).unwrap();
+    assert_eq!(big, u128::MAX);
+    assert_eq!(big.to_u128(), Some(u128::MAX));
+    assert_eq!(big.to_u64(), None);
+    assert!(<LBNum as Num>::from_str_radix("12a", 10).is_err());
+    assert!(<LBNum as Num>::from_str_radix("", 10).is_err());
+
+    let (five, seven) = (LBNum::from(5_u8), LBNum::from(7_u8));
+    assert_eq!(CheckedSub::checked_sub(&five, &seven), None);
+    assert_eq!(CheckedSub::checked_sub(&seven, &five), Some(LBNum::from(2_u8)));
+    assert_eq!(five.checked_div(&LBNum::ZERO), None);
+    assert_eq!(five.saturating_sub(seven), 0_u8);
+    assert_eq!(LBNum::from(3_u8).pow(80_u32), LBNum::from(3_u128.pow(80)));
+
+    assert_eq!(LBNum::from_i64(-1), None);
+    assert_eq!(LBNum::from_f64(1e30).unwrap(), 1_000_000_000_000_000_019_884_624_838_656_u128);
+    assert_eq!(LBNum::from_f64(2.5).unwrap(), 2_u8);
+    assert_eq!(LBNum::from_f64(-0.5).unwrap(), 0_u8);
+    assert_eq!(LBNum::from_f64(-1.0), None);
+    for &value in &[0_u128, 1, 1 << 53, (1 << 53) + 1, (1 << 54) + 3, u128::MAX] {
+        assert_eq!(LBNum::from(value).to_f64(), Some(value as f64), "{value}");
+    }
+    assert_eq!(LBNum::from(2_u8).pow(1023_u32).to_f64(), Some(2_f64.powi(1023)));
+    assert_eq!(LBNum::from(2_u8).pow(1024_u32).to_f64(), Some(f64::INFINITY));
+}
+
+#[test]
+fn lb_from_str() {
+    use crate::linkedbytes::ParseLBNumErrorKind;
+
+    let values: &[u128] = &[0, 1, 127, 128, 255, 1_000_000_007, 0xFFFF_FFFF_FFFF_FFFF, u128::MAX];
+    for &value in values {
+        let number = LBNum::from(value);
+        for radix in 2..=36 {
+            let string = number.clone().into_string_with_radix(radix);
+            assert_eq!(LBNum::from_str_radix(&string, radix.into()).unwrap(), value, "{string} in radix {radix}");
+            assert_eq!(LBNum::from_str_radix(&string.to_lowercase(), radix.into()).unwrap(), value);
+        }
+        assert_eq!(alloc::format!("{number}").parse::<LBNum>().unwrap(), value);
+        assert_eq!(alloc::format!("0x{number:x}").parse::<LBNum>().unwrap(), value);
+        assert_eq!(alloc::format!("0o{number:o}").parse::<LBNum>().unwrap(), value);
+        assert_eq!(alloc::format!("0b{number:b}").parse::<LBNum>().unwrap(), value);
+    }
+    let huge = "9".repeat(500);
+    assert_eq!(huge.parse::<LBNum>().unwrap().into_string_with_radix(10), huge);
+
+    assert_eq!("+1_000__000_".parse::<LBNum>().unwrap(), 1_000_000_u32);
+    assert_eq!(LBNum::from_str_radix("0XfF", 16).unwrap(), 255_u8);
+    assert_eq!(LBNum::from_str_radix("0b1", 16).unwrap(), 0xB1_u8);
+    let error = |src: &str, radix| {
+        let error = LBNum::from_str_radix(src, radix).unwrap_err();
+        (error.kind(), error.position())
+    };
+    assert_eq!(error("", 10), (ParseLBNumErrorKind::NoDigits, 0));
+    assert_eq!(error("0x", 16), (ParseLBNumErrorKind::NoDigits, 2));
+    assert_eq!(error("_1", 10), (ParseLBNumErrorKind::InvalidDigit('_'), 0));
+    assert_eq!(error("0x_1", 16), (ParseLBNumErrorKind::InvalidDigit('_'), 2));
+    assert_eq!(error("12 3", 10), (ParseLBNumErrorKind::InvalidDigit(' '), 2));
+    assert_eq!(error("ñ2", 10), (ParseLBNumErrorKind::InvalidDigit('ñ'), 0));
+    assert_eq!(error("1ñ2", 2), (ParseLBNumErrorKind::InvalidDigit('ñ'), 1));
+    assert_eq!(error("102", 2), (ParseLBNumErrorKind::InvalidDigit('2'), 2));
+    assert_eq!("-1".parse::<LBNum>().unwrap_err().kind(), ParseLBNumErrorKind::InvalidDigit('-'));
+}
+
+#[test]
+fn lb_int() {
+    use crate::{LBInt, DivRem};
+
+    let values: &[i128] = &[
+        0, 1, -1, 2, -2, 127, -128, 128, -129, 1_000_000_007, -1_000_000_007,
+        i64::MIN as i128, i64::MAX as i128, i128::MIN + 1, i128::MAX,
+    ];
+    for &lhs in values {
+        let big_lhs = LBInt::from(lhs);
+        assert_eq!(i128::try_from(&big_lhs), Ok(lhs));
+        assert_eq!(i64::try_from(&big_lhs).ok(), i64::try_from(lhs).ok());
+        assert_eq!(u8::try_from(&big_lhs).ok(), u8::try_from(lhs).ok());
+        assert_eq!(-&big_lhs, -lhs);
+        assert_eq!(alloc::format!("{big_lhs}"), alloc::format!("{lhs}"));
+        assert_eq!(alloc::format!("{big_lhs}").parse::<LBInt>().unwrap(), lhs);
+        assert_eq!(LBInt::decode_prefix(&big_lhs.to_bytes()).unwrap().0, lhs);
+        for &rhs in values {
+            let big_rhs = LBInt::from(rhs);
+            assert_eq!(big_lhs.cmp(&big_rhs), lhs.cmp(&rhs), "{lhs} <=> {rhs}");
+            assert_eq!(big_lhs.partial_cmp(&rhs), Some(lhs.cmp(&rhs)), "{lhs} <=> {rhs}");
+            assert_eq!(rhs.partial_cmp(&big_lhs), Some(rhs.cmp(&lhs)), "{rhs} <=> {lhs}");
+            if let Some(sum) = lhs.checked_add(rhs) {
+                assert_eq!(&big_lhs + &big_rhs, sum, "{lhs} + {rhs}");
+            }
+            if let Some(difference) = lhs.checked_sub(rhs) {
+                assert_eq!(&big_lhs - &big_rhs, difference, "{lhs} - {rhs}");
+            }
+            if let Some(product) = lhs.checked_mul(rhs) {
+                assert_eq!(&big_lhs * &big_rhs, product, "{lhs} * {rhs}");
+            }
+            if rhs != 0 {
+                let (quotient, remainder) = big_lhs.clone().div_rem(&big_rhs);
+                assert_eq!(quotient, lhs / rhs, "{lhs} / {rhs}");
+                assert_eq!(remainder, lhs % rhs, "{lhs} % {rhs}");
+            }
+        }
+    }
+    // i128::MIN only converts back to i128.
+    let min = LBInt::from(i128::MIN);
+    assert_eq!(i128::try_from(&min), Ok(i128::MIN));
+    assert!(i128::try_from(&(min - 1_u8)).is_err());
+    // The zigzag encoding keeps small numbers of both signs in one byte.
+    assert_eq!(LBInt::from(-64_i8).to_bytes(), [127]);
+    assert_eq!(LBInt::from(63_i8).to_bytes(), [126]);
+    assert_eq!(LBInt::from(64_i8).to_bytes().len(), 2);
+    assert_eq!(LBInt::from(0_i8) * -5_i8, 0_u8);
+    assert!(!(LBInt::from(0_i8) * -5_i8).is_negative());
+    assert!(LBInt::from(-1_i8) < 0_u8 && LBInt::from(u128::MAX) > i128::MAX);
+    assert_eq!(LBInt::from(i8::MIN), i8::MIN);
+    assert_eq!(alloc::format!("{:+#x}", LBInt::from(-255_i16)), "-0xff");
+    assert_eq!("-+1".parse::<LBInt>().unwrap_err().position(), 1);
+    assert_eq!("--1".parse::<LBInt>().unwrap_err().position(), 1);
+}
+
+#[test]
+fn lb_string() {
+    use crate::LBString;
+    use alloc::{string::String, vec::Vec};
+
+    // NUL takes a single zero byte rather than disappearing like a zero Linked Bytes number would.
+    let nul = "a\0b".chars().collect::<LBString>();
+    assert_eq!(nul.inner().iter_le().map(LinkedByte::into_inner).collect::<Vec<_>>(), [b'a', 0, b'b']);
+    assert_eq!(String::from(nul.clone()), "a\0b");
+    assert_eq!(nul.len(), 3);
+
+    let mut string = LBString::new();
+    assert!(string.is_empty() && string.pop().is_none());
+    string.push('Ж');
+    string.push_str("ello");
+    string.insert(0, '¡');
+    string.insert_str(6, ", мир 🌍");
+    string.extend(['!', '?'].iter());
+    assert_eq!(string, "¡Жello, мир 🌍!?");
+    assert_eq!(string.len(), 15);
+    assert_eq!(string.pop(), Some('?'));
+    assert_eq!(string.remove(1), 'Ж');
+    assert_eq!(string.remove(0), '¡');
+    assert_eq!(string, "ello, мир 🌍!");
+    string.truncate(100);
+    assert_eq!(string.len(), 12);
+    string.truncate(4);
+    assert_eq!(string, "ello");
+    string.clear();
+    assert!(string.is_empty());
+
+    let string = LBString::from("αβγ, αβ, 🌍");
+    assert_eq!(string.find('β'), Some(1));
+    assert_eq!(string.find("αβ,"), Some(5));
+    assert_eq!(string.find(&LBString::from("🌍")), Some(9));
+    assert_eq!(string.find(&String::new()), Some(0));
+    assert_eq!(string.find("γβ"), None);
+    // The last byte of Ā (U+0100) is the same as the encoding of U+0002, which must not match in the middle of a codepoint.
+    assert_eq!(LBString::from("Ā").find('\u{2}'), None);
+    assert!(!LBString::from("Ā").ends_with('\u{2}'));
+    assert!(LBString::from("Ā\u{2}").ends_with('\u{2}'));
+    assert!(string.contains(", "));
+    assert!(string.starts_with("αβγ") && !string.starts_with("β"));
+    assert!(string.ends_with('🌍') && string.ends_with("") && !string.ends_with("αβ"));
+
+    let parts = |string: &LBString, pattern: &str| string.split(pattern).map(String::from).collect::<Vec<_>>();
+    assert_eq!(parts(&string, ", "), ["αβγ", "αβ", "🌍"]);
+    assert_eq!(parts(&string, "αβ"), ["", "γ, ", ", 🌍"]);
+    assert_eq!(parts(&LBString::from("aЖ"), ""), ["", "a", "Ж", ""]);
+    assert_eq!(parts(&LBString::new(), ","), [""]);
+
+    assert_eq!(string.slice(5..7), "αβ");
+    assert_eq!(string.slice(9..), "🌍");
+    assert_eq!(string.slice(..=2), "αβγ");
+    assert_eq!(string.slice(..), string);
+    let (start, end) = (3, 2);
+    assert_eq!(string.checked_slice(start..end), None); // Decreasing ranges are out of bounds.
+    assert_eq!(string.checked_slice(..11), None);
+    assert_eq!(string.checked_slice(10..), Some(LBString::new()));
+
+    let (left, right) = (LBString::from("Ж"), LBString::from("🌍"));
+    assert_eq!(&left + &right, "Ж🌍");
+    assert_eq!(left.clone() + &right + ", " + right.clone(), "Ж🌍, 🌍");
+    assert_eq!(LBString::concat(&[left.clone(), right, left]), "Ж🌍Ж");
+}
+
+#[test]
+fn hb_from_primitive() {
+    use crate::{HBNum, HeadByte, Sign};
+
+    let parts = |number: &HBNum| (
+        number.headbyte().sign(),
+        number.exponent().map(crate::headbyte::Exponent::into_inner),
+        number.coefficient_le_iter().collect::<alloc::vec::Vec<_>>(),
+    );
+    assert_eq!(HBNum::from(0_u8).headbyte(), HeadByte::ZERO);
+    assert_eq!(HBNum::from(0_i64).headbyte(), HeadByte::ZERO);
+    assert_eq!(parts(&HBNum::from(0x1234_u32)), (Sign::Positive, None, vec![0x34, 0x12]));
+    assert_eq!(parts(&HBNum::from(-256_i16)), (Sign::Negative, None, vec![0, 1]));
+    assert_eq!(parts(&HBNum::from(i128::MIN)), (Sign::Negative, None, {
+        let mut bytes = vec![0; 15];
+        bytes.push(0x80);
+        bytes
+    }));
+    assert_eq!(HBNum::from(u128::MAX).encoded_len(), 17);
+
+    let float = |op: f64| parts(&HBNum::try_from(op).unwrap());
+    assert!(HBNum::try_from(f64::NAN).unwrap().headbyte().is_nan());
+    assert_eq!(HBNum::try_from(f32::INFINITY).unwrap().headbyte(), HeadByte::INFINITY);
+    assert_eq!(HBNum::try_from(f64::NEG_INFINITY).unwrap().headbyte(), HeadByte::NEG_INFINITY);
+    assert_eq!(HBNum::try_from(-0.0).unwrap().headbyte(), HeadByte::ZERO);
+    assert_eq!(float(1.0), (Sign::Positive, None, vec![1]));
+    assert_eq!(float(-7.0), (Sign::Negative, None, vec![7]));
+    assert_eq!(float(1500.0), (Sign::Positive, None, vec![0xDC, 0x05]));
+    assert_eq!(float(1e22), (Sign::Positive, Some(22), vec![1]));
+    assert_eq!(float(0.5), (Sign::Positive, Some(0x81), vec![5]));
+    assert_eq!(float(-0.125), (Sign::Negative, Some(0x83), vec![125]));
+    // 0.1 is actually 3602879701896397 × 2⁻⁵⁵, which takes 55 decimal places to write down exactly.
+    let (_, exponent, coefficient) = float(0.1);
+    assert_eq!(exponent, Some(0b1_0110111));
+    assert_eq!(coefficient.len(), 23);
+    assert_eq!(HBNum::try_from(0.1_f32).unwrap().exponent().unwrap().into_inner(), 0b1_0011011);
+    // 2⁴⁹⁶ needs exactly 63 bytes, 2⁵⁰⁴ doesn't fit.
+    assert_eq!(HBNum::try_from(2_f64.powi(496)).unwrap().encoded_len(), 64);
+    assert!(HBNum::try_from(2_f64.powi(504)).is_err());
+    assert!(HBNum::try_from(f64::MIN_POSITIVE).is_err());
+    assert!(HBNum::try_from(2_f64.powi(-127)).is_ok());
+    assert!(HBNum::try_from(2_f64.powi(-128)).is_err());
+}
+
+#[test]
+#[cfg_attr(feature = "clippy", allow(clippy::float_cmp))] // Correct rounding means exact results.
+#[allow(clippy::excessive_precision)] // Float literals are parsed with correct rounding too. This lint is on by default, so it needs allowing even without the clippy feature.
+#[cfg_attr(feature = "clippy", allow(clippy::cast_possible_truncation))] // Narrowing to f32 is part of what is tested.
+fn hb_into_primitive() {
+    use crate::{HBNum, HeadByte, headbyte::Exponent};
+
+    let number = |negative: bool, exponent: i8, coefficient: u128| HBNum::from_raw_parts(
+        HeadByte::from(if negative {HeadByte::SIGN_MASK} else {0}),
+        if exponent == 0 {None} else {
+            Some(Exponent::try_from(if exponent < 0 {0x80 | exponent.unsigned_abs()} else {exponent.unsigned_abs()}).unwrap())
+        },
+        {
+            let mut bytes = coefficient.to_le_bytes().to_vec();
+            while bytes.last() == Some(&0) {bytes.pop();}
+            bytes
+        },
+    );
+    for &value in &[0_i128, 1, -1, 127, -128, 255, 1 << 70, i128::MIN, i128::MAX] {
+        let hb = HBNum::from(value);
+        assert_eq!(i128::try_from(&hb), Ok(value));
+        assert_eq!(i8::try_from(&hb).ok(), i8::try_from(value).ok());
+        assert_eq!(u64::try_from(&hb).ok(), u64::try_from(value).ok());
+        assert_eq!(hb.to_f64(), value as f64);
+        assert_eq!(hb.to_f32(), value as f32);
+    }
+    assert_eq!(u32::try_from(&number(false, 3, 15)), Ok(15_000));
+    assert_eq!(i32::try_from(&number(true, -2, 1500)), Ok(-15));
+    assert!(i32::try_from(&number(false, -1, 15)).is_err());
+    assert!(u128::try_from(&number(false, 39, 1)).is_err());
+    assert!(u8::try_from(&HBNum::try_from(f64::NAN).unwrap()).is_err());
+    assert!(u8::try_from(&HBNum::try_from(f64::INFINITY).unwrap()).is_err());
+
+    // Every float which converts into HBNum has to convert back into itself.
+    for &value in &[
+        0.1, -0.1, 0.5, 1.0 / 3.0, 2.0 / 3.0, 1e-10, 123.456, 9_007_199_254_740_993.0, 1e22, 1e100,
+        core::f64::consts::PI, -core::f64::consts::E, 2_f64.powi(-127), 2_f64.powi(496),
+    ] {
+        let hb = HBNum::try_from(value).unwrap();
+        assert_eq!(hb.to_f64(), value, "{value}");
+        assert_eq!(HBNum::try_from(value as f32).unwrap().to_f32(), value as f32, "{value}");
+    }
+    // Decimals which aren't exactly representable have to be rounded correctly.
+    assert_eq!(number(false, -1, 1).to_f64(), 0.1);
+    assert_eq!(number(true, -3, 123_456).to_f64(), -123.456);
+    assert_eq!(number(false, -127, 1).to_f64(), 1e-127);
+    assert_eq!(number(false, 127, 1).to_f64(), 1e127);
+    assert_eq!(number(false, -20, 12_345_678_901_234_567_890_123).to_f64(), 123.456_789_012_345_678_901_23);
+    assert_eq!(number(false, -1, 1).to_f32(), 0.1_f32);
+    assert_eq!(number(false, 127, 1).to_f32(), f32::INFINITY);
+    assert_eq!(number(true, -127, 1).to_f32(), -0.0);
+    assert_eq!(number(false, -45, 1).to_f32(), 1e-45_f32);
+    assert!(HBNum::try_from(f64::NAN).unwrap().to_f64().is_nan());
+    assert_eq!(HBNum::try_from(f64::NEG_INFINITY).unwrap().to_f32(), f32::NEG_INFINITY);
+}
+
+/// Builds a Head Byte number with the exact exponent and coefficient given, without normalizing it. An exponent of zero means no exponent byte.
+fn hb_number(exponent: i8, coefficient: i128) -> crate::HBNum {
+    use crate::{HBNum, HeadByte, headbyte::Exponent};
+    let mut bytes = coefficient.unsigned_abs().to_le_bytes().to_vec();
+    while bytes.last() == Some(&0) {bytes.pop();}
+    let hb = HeadByte::from(if coefficient < 0 {HeadByte::SIGN_MASK} else {0});
+    HBNum::from_raw_parts(hb, if exponent == 0 {None} else {Some(Exponent::from_i8(exponent).unwrap())}, bytes)
+}
+
+#[test]
+fn hb_arith() {
+    use crate::{HBNum, HeadByte, headbyte::Exponent};
+    use alloc::string::ToString;
+
+    let same = |lhs: HBNum, rhs: HBNum| assert_eq!(lhs.to_bytes(), rhs.to_bytes());
+
+    // Exact decimal arithmetic, with trailing zeros of fractions removed.
+    same(hb_number(-1, 1) + hb_number(-1, 2), hb_number(-1, 3));
+    same(hb_number(-2, 150) + hb_number(-2, 150), HBNum::from(3));
+    same(HBNum::from(10) - HBNum::from(25), HBNum::from(-15));
+    same(hb_number(2, 3) - hb_number(-1, 5), hb_number(-1, 2995));
+    same(hb_number(-3, -125) * HBNum::from(8), HBNum::from(-1));
+    same(HBNum::from(-7) / HBNum::from(2), hb_number(-1, -35));
+    same(HBNum::from(1) / HBNum::from(1024), hb_number(-10, 9_765_625));
+    let mut accumulator = HBNum::from(1);
+    accumulator /= hb_number(-1, 5);
+    accumulator -= &HBNum::from(2);
+    same(accumulator, HBNum::ZERO);
+    // Inexact results are rounded to the nearest number, ties to even.
+    let two_thirds = HBNum::from(2) / HBNum::from(3);
+    assert_eq!(two_thirds.exponent(), Some(Exponent::from_i8(-127).unwrap()));
+    assert!((two_thirds.to_f64() - 2.0 / 3.0).abs() < f64::EPSILON);
+    same(hb_number(-127, 1) / HBNum::from(2), HBNum::ZERO);
+    same(hb_number(-127, 3) / HBNum::from(2), hb_number(-127, 2));
+    same(hb_number(-127, 1) / HBNum::from(-3), HBNum::ZERO);
+    let max = HBNum::from_raw_parts(HeadByte::ZERO, None, vec![0xFF; 63]);
+    // 2⁵⁰⁴ only fits into 62 bytes with 3 digits off, after which it's small enough to be stored as an integer.
+    let rounded = &max + HBNum::from(1);
+    assert_eq!(rounded.exponent(), None);
+    assert!(rounded.to_string().ends_with("000"));
+
+    // Overflow gives infinities, or None from the checked methods.
+    let huge = hb_number(127, 10_i128.pow(30));
+    assert!(huge.checked_mul(&huge).is_none());
+    assert!((&huge * -&huge).headbyte() == HeadByte::NEG_INFINITY);
+    assert!(hb_number(100, 5).checked_mul(&hb_number(100, 3)).is_some());
+    assert!(HBNum::from(1).checked_div(&HBNum::ZERO).is_none());
+    // The checked methods never round, unlike the operators.
+    assert!(max.checked_add(&HBNum::from(1)).is_none());
+    assert!(HBNum::from(1).checked_div(&HBNum::from(3)).is_none());
+    same(HBNum::from(1).checked_div(&HBNum::from(4)).unwrap(), hb_number(-2, 25));
+    same(HBNum::from(7).checked_sub(&hb_number(-1, 5)).unwrap(), hb_number(-1, 65));
+    // Special values follow IEEE 754.
+    assert!((HBNum::from(-1) / HBNum::ZERO).headbyte() == HeadByte::NEG_INFINITY);
+    assert!((HBNum::ZERO / HBNum::ZERO).headbyte().is_nan());
+    assert!((HBNum::INFINITY - HBNum::INFINITY).headbyte().is_nan());
+    assert!((HBNum::INFINITY * HBNum::ZERO).headbyte().is_nan());
+    assert!((HBNum::NAN + HBNum::from(1)).headbyte().is_nan());
+    assert!((HBNum::from(1) / HBNum::NEG_INFINITY).headbyte() == HeadByte::ZERO);
+    assert!((HBNum::NEG_INFINITY * HBNum::from(-2)).headbyte() == HeadByte::INFINITY);
+
+    assert!(-HeadByte::INFINITY == HeadByte::NEG_INFINITY);
+    assert!((-HeadByte::NAN).is_nan());
+    assert_eq!(Exponent::try_from(0b1_0000011_u8).unwrap().invert(), Exponent::try_from(3_u8).unwrap());
+    assert_eq!(Exponent::try_from(0_u8).unwrap().invert(), Exponent::try_from(0_u8).unwrap());
+    let exponent = |op: i8| Exponent::from_i8(op).unwrap();
+    assert_eq!(exponent(100).checked_mul(exponent(-120)), Some(exponent(-20)));
+    assert_eq!(exponent(100).checked_mul(exponent(27)), Some(exponent(127)));
+    assert_eq!(exponent(-100).checked_mul(exponent(-28)), None);
+    assert_eq!(exponent(5).checked_div(exponent(5)), Some(exponent(0)));
+}
+
+#[test]
+fn hb_cmp() {
+    use crate::{HBNum, HeadByte};
+    use core::cmp::Ordering;
+    use alloc::{vec::Vec, format};
+
+
+    assert_eq!(hb_number(1, 1), HBNum::from(10));
+    assert_eq!(hb_number(-2, -1500), hb_number(-1, -150));
+    assert_eq!(HBNum::from_raw_parts(HeadByte::ZERO, None, vec![0, 0]), HBNum::ZERO);
+    #[cfg(feature = "std")] {
+        use core::hash::{Hash, Hasher};
+        use std::collections::hash_map::DefaultHasher;
+        let hash = |op: &HBNum| {
+            let mut hasher = DefaultHasher::new();
+            op.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&hb_number(-2, -1500)), hash(&HBNum::from(-15)));
+        assert_eq!(hash(&hb_number(-3, 1000)), hash(&HBNum::from(1)));
+        assert_eq!(hash(&HBNum::from_raw_parts(HeadByte::ZERO, None, vec![0])), hash(&HBNum::ZERO));
+    }
+    assert_ne!(hb_number(-1, 1), HBNum::ZERO);
+    assert!(hb_number(-1, 5) < HBNum::from(1));
+    assert!(hb_number(-1, -5) > HBNum::from(-1));
+    assert!(HBNum::from(-1) < HBNum::ZERO);
+    assert!(hb_number(127, 1) < HBNum::INFINITY);
+    assert!(hb_number(127, -1) > HBNum::NEG_INFINITY);
+    assert_eq!(HBNum::INFINITY, HBNum::INFINITY);
+    assert_ne!(HBNum::NAN, HBNum::NAN);
+    assert_eq!(HBNum::NAN.partial_cmp(&HBNum::ZERO), None);
+
+    let mut numbers = [HBNum::NAN, HBNum::from(3), HBNum::NEG_INFINITY, hb_number(-1, 25), HBNum::INFINITY, HBNum::from(-3)];
+    numbers.sort_by(HBNum::total_cmp);
+    let sorted = numbers.iter().map(HBNum::to_f64).collect::<Vec<_>>();
+    assert_eq!(format!("{sorted:?}"), "[-inf, -3.0, 2.5, 3.0, inf, NaN]");
+
+    assert_ne!(HeadByte::NAN, HeadByte::NAN);
+    assert!(HeadByte::NEG_INFINITY < HeadByte::from(0x81));
+    assert!(HeadByte::from(0x81) < HeadByte::ZERO);
+    assert_eq!(HeadByte::from(0x41).partial_cmp(&HeadByte::from(0x02)), None);
+    assert_eq!(HeadByte::NAN.total_cmp(HeadByte::INFINITY), Ordering::Greater);
+}
+
+#[test]
+fn hb_fmt() {
+    use crate::HBNum;
+    use alloc::format;
+
+
+    assert_eq!(format!("{}", hb_number(2, 15)), "1500");
+    assert_eq!(format!("{}", hb_number(-3, -25)), "-0.025");
+    assert_eq!(format!("{}", hb_number(-2, 1500)), "15");
+    assert_eq!(format!("{}", HBNum::ZERO), "0");
+    assert_eq!(format!("{:e}", hb_number(0, 1500)), "1.5e3");
+    assert_eq!(format!("{:E}", hb_number(-3, -25)), "-2.5E-2");
+    assert_eq!(format!("{:e}", HBNum::ZERO), "0e0");
+    // Precision rounds ties to even, exactly.
+    assert_eq!(format!("{:.1}", hb_number(-2, 25)), "0.2");
+    assert_eq!(format!("{:.1}", hb_number(-2, 35)), "0.4");
+    assert_eq!(format!("{:.2}", hb_number(-3, -25)), "-0.02");
+    assert_eq!(format!("{:.0}", hb_number(-1, 96)), "10");
+    assert_eq!(format!("{:.3}", hb_number(-1, 5)), "0.500");
+    assert_eq!(format!("{:.2e}", hb_number(-3, 9996)), "1.00e1");
+    assert_eq!(format!("{:.0e}", hb_number(0, 1500)), "2e3");
+    assert_eq!(format!("{:.3}", HBNum::from(1) / HBNum::from(3)), "0.333");
+    // Width, fill and sign are handled like for primitive numbers.
+    assert_eq!(format!("{:+08.2}", hb_number(-1, 15)), "+0001.50");
+    assert_eq!(format!("{:>8}", hb_number(-1, -15)), "    -1.5");
+
+    assert_eq!(format!("{}", HBNum::NAN), "NaN");
+    assert_eq!(format!("{:+}", HBNum::NAN), "NaN");
+    assert_eq!(format!("{:e}", HBNum::INFINITY), "Infinity");
+    assert_eq!(format!("{:.2}", HBNum::NEG_INFINITY), "-Infinity");
+}
+
+#[test]
+fn hb_from_str() {
+    use crate::{HBNum, headbyte::{Exponent, ParseHBNumErrorKind}};
+    use alloc::{string::ToString, vec::Vec, format};
+
+    let parse = |src: &str| src.parse::<HBNum>();
+    let parts = |op: &HBNum| (op.headbyte().sign(), op.exponent().map(i8::from), op.coefficient_le_iter().collect::<Vec<_>>());
+
+    assert_eq!(parts(&parse("-12.5").unwrap()), (crate::Sign::Negative, Some(-1), vec![125]));
+    assert_eq!(parts(&parse("3e-7").unwrap()), (crate::Sign::Positive, Some(-7), vec![3]));
+    assert_eq!(parts(&parse("1_000").unwrap()), (crate::Sign::Positive, None, vec![0xE8, 0x03]));
+    assert_eq!(parse("+0.000").unwrap().to_bytes(), HBNum::ZERO.to_bytes());
+    assert_eq!(parse("1.5E+3").unwrap(), HBNum::from(1500));
+    assert_eq!(parse(".25").unwrap().to_string(), "0.25");
+    assert_eq!(parts(&parse("10e127").unwrap()), (crate::Sign::Positive, Some(127), vec![10]));
+    assert_eq!(parse("1_500e-127").unwrap().exponent(), Some(Exponent::from_i8(-125).unwrap()));
+    assert_eq!(parse("1e200").unwrap().exponent(), Some(Exponent::from_i8(127).unwrap()));
+    assert_eq!(parse("1_500e-129").unwrap().exponent(), Some(Exponent::from_i8(-127).unwrap()));
+    // Scientific notation as produced by the formatter parses back, even when the written exponent is outside of the exponent byte's range.
+    let scientific = parse("35971711607947e117").unwrap();
+    assert_eq!(format!("{scientific:e}"), "3.5971711607947e130");
+    assert_eq!(parse(&format!("{scientific:e}")).unwrap(), scientific);
+    assert_eq!(parse(&format!("{:E}", -&scientific)).unwrap(), -scientific);
+    assert_eq!(parse("123456789.987654321").unwrap().to_string(), "123456789.987654321");
+    assert!(parse("inf").unwrap().headbyte().is_infinite());
+    assert_eq!(parse("-Infinity").unwrap().headbyte().sign(), crate::Sign::Negative);
+    assert!(parse("NaN").unwrap().headbyte().is_nan());
+    // The largest integer without an exponent byte ends with a zero, which must not make it need one.
+    let max = "52374249726338269920211035149241586435466272736689036631732661889538140742474792878132321477214466514414186946040961136147476104734166288853256441430010";
+    assert_eq!(parse(max).unwrap().to_string(), max);
+
+    let error = |src: &str| parse(src).map(|_| ()).map_err(|e| (e.kind(), e.position()));
+    assert_eq!(error(""), Err((ParseHBNumErrorKind::NoDigits, 0)));
+    assert_eq!(error("-."), Err((ParseHBNumErrorKind::NoDigits, 2)));
+    assert_eq!(error("1e"), Err((ParseHBNumErrorKind::NoDigits, 2)));
+    assert_eq!(error("1.2.3"), Err((ParseHBNumErrorKind::InvalidDigit('.'), 3)));
+    assert_eq!(error("_1"), Err((ParseHBNumErrorKind::InvalidDigit('_'), 0)));
+    assert_eq!(error("1e5x"), Err((ParseHBNumErrorKind::InvalidDigit('x'), 3)));
+    assert_eq!(error("1e300"), Err((ParseHBNumErrorKind::ExponentOutOfRange, 2)));
+    assert_eq!(error("-1e-128"), Err((ParseHBNumErrorKind::ExponentOutOfRange, 3)));
+    assert_eq!(error("1e-99999999999999999999"), Err((ParseHBNumErrorKind::ExponentOutOfRange, 2)));
+    assert_eq!(error("0.1e-127"), Err((ParseHBNumErrorKind::ExponentOutOfRange, 4)));
+    assert_eq!(error(&format!("-1{}", "0".repeat(300))), Err((ParseHBNumErrorKind::ExponentOutOfRange, 1)));
+    assert_eq!(error(&"9".repeat(153)), Err((ParseHBNumErrorKind::TooManyDigits, 0)));
+    assert_eq!(error("+1.00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001"), Err((ParseHBNumErrorKind::TooManyDigits, 1)));
+}
+
+#[test]
+fn canonical() {
+    use crate::{HBNum, HeadByte, LBInt, headbyte::{Exponent, HBDecodeError}, linkedbytes::InvalidLBSequence};
+
+    // Trailing decimal zeros go into the exponent, but only if that makes the number shorter.
+    assert_eq!(HBNum::from(10_u32).to_bytes(), [0b00_000001, 10]);
+    assert_eq!(HBNum::from(10_u64.pow(19)).to_bytes(), [0b01_000010, 19, 1]);
+    assert_eq!(HBNum::from(-4_660).to_bytes(), [0b10_000010, 0x34, 0x12]);
+    // Every way of writing the same value has the same encoding.
+    for src in ["1.5e3", "1500", "15e2", "1500.000", "150e1"] {
+        assert_eq!(src.parse::<HBNum>().unwrap().to_bytes(), HBNum::from(1500).to_bytes());
+    }
+
+    let raw = |hb: u8, exponent: i8, bytes: &[u8]| HBNum::from_raw_parts(
+        HeadByte::from(hb),
+        if exponent == 0 {None} else {Some(Exponent::from_i8(exponent).unwrap())},
+        bytes.to_vec(),
+    );
+    for (exponent, bytes, canonical) in [
+        (-2, &[150][..], &[0b01_000010, 0b1000_0001, 15][..]), // 1.50
+        (0, &[5, 0], &[0b00_000001, 5]),                       // A zero byte at the most significant end.
+        (3, &[0], &[0]),                                       // Zero with an exponent.
+        (2, &[15], &[0b00_000010, 0xDC, 0x05]),                // 1500 is as long without the exponent.
+    ] {
+        let mut num = raw(0, exponent, bytes);
+        assert!(!num.is_canonical());
+        let encoded = num.to_bytes();
+        assert_eq!(HBNum::from_bytes(&encoded).unwrap().to_bytes(), encoded);
+        assert_eq!(HBNum::from_bytes_strict(&encoded).unwrap_err(), HBDecodeError::NonCanonical);
+        num.normalize();
+        assert!(num.is_canonical());
+        assert_eq!(num.to_bytes(), canonical);
+        assert_eq!(HBNum::from_bytes_strict(canonical).unwrap().to_bytes(), canonical);
+    }
+    assert!(!raw(HeadByte::SIGN_MASK, 0, &[0]).is_canonical()); // -0
+    for special in [HBNum::ZERO, HBNum::NAN, HBNum::INFINITY, HBNum::NEG_INFINITY] {
+        assert!(special.is_canonical());
+    }
+    let (num, len) = HBNum::parse_strict(&[0b00_000001, 7, 0xAB]).unwrap();
+    assert_eq!((num.to_bytes(), len), (vec![0b00_000001, 7], 2));
+
+    let mut num = LBNum::try_from(vec![LinkedByte::from(5).into_linked(), LinkedByte::from(0)]).unwrap();
+    assert!(!num.is_canonical());
+    num.normalize();
+    assert!(num.is_canonical());
+    assert_eq!(num.to_bytes(), [5]);
+    assert_eq!(LBNum::decode_prefix_strict(&[0b1_0000101, 0, 1]).unwrap_err(), InvalidLBSequence);
+    assert_eq!(LBNum::decode_prefix_strict(&[0b1_0000000, 0]).unwrap_err(), InvalidLBSequence); // Zero takes exactly one byte.
+    assert_eq!(LBNum::decode_prefix_strict(&[0, 0]).unwrap(), (LBNum::ZERO, 1));
+    assert_eq!(LBNum::decode_prefix_strict(&[0b1_0000101, 1]).unwrap(), (LBNum::from(133_u8), 2));
+    assert_eq!(LBInt::decode_prefix_strict(&[0b1_0000001, 0]).unwrap_err(), InvalidLBSequence);
+    assert_eq!(LBInt::decode_prefix_strict(&[1]).unwrap().0, LBInt::from(-1));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde() {
+    use crate::{HBNum, HeadByte, LBString, Sign, headbyte::Exponent};
+    use serde_test::{Token, Configure, assert_tokens, assert_de_tokens, assert_de_tokens_error};
+
+    let num = LBNum::from(300_u16);
+    assert_tokens(&num.clone().readable(), &[Token::Str("300")]);
+    assert_tokens(&num.clone().compact(), &[Token::Bytes(&[0b1_0101100, 0b0_0000010])]);
+    assert_de_tokens(&num.clone().readable(), &[Token::U64(300)]);
+    assert_de_tokens(&num.compact(), &[Token::Seq {len: Some(2)}, Token::U8(0b1_0101100), Token::U8(0b0_0000010), Token::SeqEnd]);
+    assert_de_tokens_error::<serde_test::Compact<LBNum>>(&[Token::Bytes(&[0b1_0000001])], "invalid value: byte array, expected a Linked Bytes number");
+
+    let string = LBString::from("Ж!");
+    assert_tokens(&string.clone().readable(), &[Token::Str("Ж!")]);
+    assert_tokens(&string.compact(), &[Token::Bytes(&[0b1_0010110, 0b0_0001000, 0b0_0100001])]);
+    assert_de_tokens_error::<serde_test::Compact<LBString>>(&[Token::Bytes(&[0b1_0000000, 0b1_0111000, 0b0_0000011])], "invalid value: byte array, expected a Linked Bytes string"); // U+DC00 is a surrogate.
+
+    let num = "-12.5".parse::<HBNum>().unwrap();
+    assert_tokens(&num.clone().readable(), &[Token::Str("-12.5")]);
+    assert_tokens(&num.compact(), &[Token::Bytes(&[0b11_000010, 0b1000_0001, 125])]);
+    assert_de_tokens(&HBNum::from(-3).readable(), &[Token::I64(-3)]);
+    assert_de_tokens(&HBNum::try_from(0.25).unwrap().readable(), &[Token::F64(0.25)]);
+    assert_tokens(&HBNum::INFINITY.readable(), &[Token::Str("Infinity")]);
+    assert_de_tokens_error::<serde_test::Readable<HBNum>>(&[Token::Str("1e999")], "the number requires an exponent outside of the range from -127 to 127");
+    assert_de_tokens_error::<serde_test::Compact<HBNum>>(&[Token::Bytes(&[0b00_000001, 1, 2])], "invalid value: byte array, expected a Head Byte number");
+    // Self-describing formats are asked for whatever they have, be it a string or a number.
+    assert_eq!(serde_json::from_str::<LBNum>("300").unwrap(), LBNum::from(300_u16));
+    assert_eq!(serde_json::from_str::<LBNum>("\"300\"").unwrap(), LBNum::from(300_u16));
+    assert!(serde_json::from_str::<LBNum>("-3").is_err());
+    assert_eq!(serde_json::from_str::<HBNum>("-3").unwrap(), HBNum::from(-3));
+    assert_eq!(serde_json::from_str::<HBNum>("0.25").unwrap(), HBNum::try_from(0.25).unwrap());
+    assert_eq!(serde_json::to_string(&HBNum::try_from(0.25).unwrap()).unwrap(), "\"0.25\"");
+    // A length which is way off doesn't make the sequence allocate for it upfront.
+    assert_de_tokens(&LBNum::from(1_u8).compact(), &[Token::Seq {len: Some(usize::MAX)}, Token::U8(1), Token::SeqEnd]);
+
+    assert_tokens(&HeadByte::NEG_INFINITY, &[Token::U8(0b11_000000)]);
+    assert_tokens(&Exponent::from_i8(-5).unwrap(), &[Token::I8(-5)]);
+    assert_de_tokens_error::<Exponent>(&[Token::I8(-128)], "invalid value: integer `-128`, expected an exponent from -127 to 127");
+    assert_tokens(&Sign::Negative, &[Token::UnitVariant {name: "Sign", variant: "Negative"}]);
+    assert_de_tokens_error::<Sign>(&[Token::UnitVariant {name: "Sign", variant: "Zero"}], "unknown variant `Zero`, expected `Positive` or `Negative`");
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn random() {
+    use crate::{HBNum, HeadByte, Sign, headbyte::Exponent, linkedbytes::random::{RandomBits, ExactBits, ExactBytes, UniformBelow, GenLBNum}};
+    use rand::{Rng, SeedableRng, distributions::Distribution};
+    use rand_pcg::Pcg64Mcg;
+
+    let mut rng = Pcg64Mcg::seed_from_u64(42);
+    for bits in [0, 1, 7, 8, 63, 64, 200] {
+        assert!(RandomBits(bits).sample_iter(&mut rng).take(16).all(|num: LBNum| num.bit_length() <= bits));
+        assert!(ExactBits(bits).sample_iter(&mut rng).take(16).all(|num: LBNum| num.bit_length() == bits));
+    }
+    for len in [1, 2, 30] {
+        assert!(ExactBytes::new(len).sample_iter(&mut rng).take(16).all(|num: LBNum| num.to_bytes().len() == len));
+    }
+    let mut seen = [false; 10];
+    for num in UniformBelow::new(LBNum::from(10_u8)).sample_iter(&mut rng).take(200) {
+        seen[usize::try_from(num).unwrap()] = true;
+    }
+    assert!(seen.iter().all(|&seen| seen));
+    let bound = LBNum::from(3_u8).pow(100);
+    assert!((0..32).all(|_| rng.gen_lbnum_below(&bound) < bound));
+    assert_eq!(rng.gen_lbnum_below(&LBNum::from(1_u8)), 0_u8);
+    let (low, high) = (LBNum::from(u64::MAX), LBNum::from(u64::MAX) + 3_u8);
+    assert!((0..32).all(|_| (low.clone()..high.clone()).contains(&rng.gen_lbnum_range(&low, &high))));
+    assert!(rng.gen_lbnum_bits(100).bit_length() <= 100);
+
+    let exponents = (0..4000).map(|_| i8::from(rng.gen::<Exponent>())).collect::<Vec<_>>();
+    assert!(exponents.contains(&-127) && exponents.contains(&127) && exponents.contains(&0));
+    let numbers = (0..4000).map(|_| rng.gen::<HBNum>()).collect::<Vec<_>>();
+    for num in &numbers {
+        assert_eq!(HBNum::from_bytes(&num.to_bytes()).unwrap().to_bytes(), num.to_bytes());
+    }
+    assert!(numbers.iter().any(|num| num.headbyte().is_nan()));
+    assert!(numbers.iter().any(|num| num.headbyte() == HeadByte::INFINITY));
+    assert!(numbers.iter().any(|num| num.headbyte() == HeadByte::NEG_INFINITY));
+    assert!(numbers.iter().any(|num| num.exponent().is_some()));
+    assert!((0..64).any(|_| rng.gen::<Sign>() == Sign::Negative));
+
+    // Generators from rand can be used for primality testing too.
+    let prime = crate::primality::random_prime(128, &mut rng);
+    assert!(crate::primality::is_probable_prime(&prime, 4, &mut rng));
+}
@@ -43,6 +43,30 @@ pub trait Gcd<Rhs = Self>: Sealed {
     ///
     /// [0]: https://en.wikipedia.org/wiki/Euclidean_algorithm "Euclidean Algorithm on Wikipedia"
     fn gcd(lhs: Self, rhs: Rhs) -> Self;
+    /// Performs the calculation of the least common multiple.
+    ///
+    /// This is usually done by dividing one of the operands by the GCD and multiplying the result by the other operand. The least common multiple of zero and any number is zero.
+    fn lcm(lhs: Self, rhs: Rhs) -> Self;
+}
+/// Calculating the greatest common divisor along with the [Bézout coefficients][0].
+///
+/// The coefficients `x` and `y` satisfy `lhs·x + rhs·y = gcd(lhs, rhs)`, which makes this the basic building block for modular inverses and solving linear Diophantine equations. Since one of the coefficients is negative in most cases, their type is separate from the type of the operands.
+///
+/// This trait is **sealed**, i.e. cannot be implemented for types outside of the `bigbit` crate, which allows adding new methods to the trait without breaking changes and prevents logic errors.
+///
+/// [0]: https://en.wikipedia.org/wiki/B%C3%A9zout%27s_identity "Bézout's identity on Wikipedia"
+pub trait ExtendedGcd<Rhs = Self>: Gcd<Rhs> {
+    /// The type for the Bézout coefficients.
+    type Coefficient;
+    /// Performs the calculation of the greatest common divisor and the Bézout coefficients.
+    ///
+    /// The elements of the returned tuple are the GCD, the coefficient for `lhs` and the coefficient for `rhs`, in that order.
+    ///
+    /// Most implementations use the [extended Euclidean algorithm][0] for this.
+    ///
+    /// [0]: https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm "Extended Euclidean Algorithm on Wikipedia"
+    fn extended_gcd(lhs: Self, rhs: Rhs) -> (Self, Self::Coefficient, Self::Coefficient)
+    where Self: Sized;
 }
 
 /// Performs the `+` operation at the specified coefficient byte of a BigBit number. This is used by the multiplication implementations as a simple and fast way of multiplying something by a power of 128 or 256 (former in the case of Linked Bytes, latter for Head Byte and Extended Byte) and then adding the result to another number.
@@ -79,12 +103,14 @@ pub trait AddAssignAt<Rhs = Self>: Sealed {
     fn add_assign_at(&mut self, index: usize, rhs: Rhs);
 }
 
-use seal::Sealed;
+pub(crate) use seal::Sealed;
 mod seal {
     use crate::{
         linkedbytes::*,
         headbyte::*,
+        extheadbyte::*,
     };
+    use alloc::string::String;
 
     /// Disallows outside implementations for the traits, allowing for breaking changes to those traits in minor/patch releases.
     pub trait Sealed {}
@@ -97,9 +123,19 @@ mod seal {
     allow_sealed_for! {
         LBNum
         LBNumRef<'_>
+        LBInt
         LinkedByte
         
         HBNum
         HeadByte
+
+        EHBNum
+        ExtHeadByte
+
+        // Patterns for searching in LBString.
+        char
+        &str
+        &String
+        &LBString
     }
 }
\ No newline at end of file